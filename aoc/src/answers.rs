@@ -0,0 +1,166 @@
+use std::{collections::BTreeMap, fmt, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+/// Default location of the recorded answers, next to the `inputs` directory.
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+/// Accepted answers, stored as
+///
+/// ```toml
+/// [day_05]
+/// part1 = 600279879
+/// part2 = 20191102
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers(BTreeMap<String, DayAnswers>);
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<Answer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        s.parse()
+            .map_or_else(|_| Answer::Text(s.to_string()), Answer::Number)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AnswersError {
+    #[error("Could not access {path}: {source}")]
+    Io { path: String, source: io::Error },
+    #[error("Invalid answers file {path}: {source}")]
+    Invalid {
+        path: String,
+        source: toml::de::Error,
+    },
+    #[error("Could not serialize answers: {0}")]
+    Serialize(#[from] toml::ser::Error),
+}
+
+// 5 -> "day_05"
+fn key(day: u8) -> String {
+    format!("day_{:02}", day)
+}
+
+impl Answers {
+    /// Loads the answers file, a missing file meaning no known answer yet.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content).map_err(|source| AnswersError::Invalid {
+                path: path.display().to_string(),
+                source,
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(source) => Err(AnswersError::Io {
+                path: path.display().to_string(),
+                source,
+            }),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AnswersError> {
+        std::fs::write(path, toml::to_string(self)?).map_err(|source| AnswersError::Io {
+            path: path.display().to_string(),
+            source,
+        })
+    }
+
+    fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        let answers = self.0.get(&key(day))?;
+        match part {
+            1 => answers.part1.as_ref(),
+            _ => answers.part2.as_ref(),
+        }
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected.to_string() == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: &str) {
+        let answers = self.0.entry(key(day)).or_default();
+        let slot = match part {
+            1 => &mut answers.part1,
+            _ => &mut answers.part2,
+        };
+        *slot = Some(Answer::from(answer));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_and_record() {
+        let mut answers: Answers = toml::from_str(
+            r#"[day_05]
+part1 = 35
+[day_07]
+part2 = "5905""#,
+        )
+        .unwrap();
+        assert_eq!(answers.check(5, 1, "35"), Verdict::Pass);
+        assert_eq!(
+            answers.check(5, 1, "36"),
+            Verdict::Fail {
+                expected: "35".to_string()
+            }
+        );
+        assert_eq!(answers.check(5, 2, "46"), Verdict::Unknown);
+        assert_eq!(answers.check(7, 2, "5905"), Verdict::Pass);
+        assert_eq!(answers.check(8, 1, "2"), Verdict::Unknown);
+
+        answers.record(5, 2, "46");
+        answers.record(8, 1, "abc");
+        assert_eq!(answers.check(5, 2, "46"), Verdict::Pass);
+        assert_eq!(
+            toml::to_string(&answers).unwrap(),
+            r#"[day_05]
+part1 = 35
+part2 = 46
+
+[day_07]
+part2 = "5905"
+
+[day_08]
+part1 = "abc"
+"#
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use std::{
+    error::Error,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use aoc_common::Solution;
+use serde::Serialize;
+
+/// Step of a solution that is timed on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Step {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Step {
+    fn of_part(part: u8) -> Self {
+        match part {
+            1 => Step::Part1,
+            2 => Step::Part2,
+            _ => unreachable!("part is validated by the CLI"),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Step::Parse => "parse",
+            Step::Part1 => "part 1",
+            Step::Part2 => "part 2",
+        }
+    }
+}
+
+/// Timings of one step over all iterations, in nanoseconds once serialized.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub step: Step,
+    pub iterations: usize,
+    #[serde(rename = "min_ns", serialize_with = "as_nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "as_nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", serialize_with = "as_nanos")]
+    pub mean: Duration,
+}
+
+fn as_nanos<S: serde::Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+impl Measurement {
+    fn new(day: u8, step: Step, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let iterations = samples.len();
+        let median = if iterations.is_multiple_of(2) {
+            (samples[iterations / 2 - 1] + samples[iterations / 2]) / 2
+        } else {
+            samples[iterations / 2]
+        };
+        Measurement {
+            day,
+            step,
+            iterations,
+            min: samples[0],
+            median,
+            mean: mean(samples.iter().sum(), iterations),
+        }
+    }
+}
+
+// `total / iterations`, even for more iterations than a u32 holds
+fn mean(total: Duration, iterations: usize) -> Duration {
+    const NANOS_PER_SEC: u128 = 1_000_000_000;
+    let nanos = total.as_nanos() / iterations as u128;
+    Duration::new(
+        (nanos / NANOS_PER_SEC) as u64,
+        (nanos % NANOS_PER_SEC) as u32,
+    )
+}
+
+fn time<T, E>(iterations: usize, mut f: impl FnMut() -> Result<T, E>) -> Result<Vec<Duration>, E> {
+    (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            let result = f()?;
+            let elapsed = start.elapsed();
+            black_box(result);
+            Ok(elapsed)
+        })
+        .collect()
+}
+
+/// Times the parse step, then every requested part on a single parsed value.
+pub fn bench<S: Solution>(
+    input: &str,
+    parts: &[u8],
+    iterations: usize,
+) -> Result<Vec<Measurement>, Box<dyn Error>> {
+    assert!(iterations > 0, "at least one iteration is needed");
+    let mut measurements = vec![Measurement::new(
+        S::DAY,
+        Step::Parse,
+        time(iterations, || S::parse(black_box(input)))?,
+    )];
+    let parsed = S::parse(input)?;
+    for &part in parts {
+        let samples = match part {
+            1 => time(iterations, || S::part1(black_box(&parsed)).map(|_| ()))?,
+            _ => time(iterations, || S::part2(black_box(&parsed)).map(|_| ()))?,
+        };
+        measurements.push(Measurement::new(S::DAY, Step::of_part(part), samples));
+    }
+    Ok(measurements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_measurement() {
+        let m = Measurement::new(5, Step::Parse, vec![ms(4), ms(1), ms(10), ms(3)]);
+        assert_eq!(
+            (m.min, m.median, m.mean),
+            (ms(1), ms(3) + ms(1) / 2, ms(4) + ms(1) / 2)
+        );
+        let m = Measurement::new(5, Step::Part1, vec![ms(4), ms(1), ms(10)]);
+        assert_eq!((m.iterations, m.median), (3, ms(4)));
+        assert_eq!(mean(ms(1 << 33), 1 << 32), ms(2));
+        assert_eq!(mean(Duration::MAX, 1), Duration::MAX);
+    }
+
+    #[test]
+    fn test_json() {
+        let m = Measurement::new(5, Step::Part2, vec![ms(1)]);
+        assert_eq!(
+            serde_json::to_string(&m).unwrap(),
+            r#"{"day":5,"step":"part2","iterations":1,"min_ns":1000000,"median_ns":1000000,"mean_ns":1000000}"#
+        );
+    }
+}
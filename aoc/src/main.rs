@@ -0,0 +1,255 @@
+mod answers;
+mod bench;
+mod registry;
+
+use std::{error::Error, path::PathBuf, process::ExitCode, time::Duration};
+
+use answers::{Answers, Verdict, DEFAULT_ANSWERS_FILE};
+use aoc_common::{input::DEFAULT_INPUTS_DIR, InputSource};
+use clap::{Args, Parser, Subcommand};
+use registry::{Day, PartAnswer};
+
+#[derive(Parser)]
+#[command(about = "Run the Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every registered day if none is given
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+        /// Also print the time spent parsing and solving each part
+        #[arg(short, long)]
+        time: bool,
+    },
+    /// Compare the computed answers with the recorded ones
+    Verify {
+        #[command(flatten)]
+        selection: Selection,
+        /// File holding the accepted answers
+        #[arg(long, default_value = DEFAULT_ANSWERS_FILE)]
+        answers: PathBuf,
+        /// Record the answers that are not known yet
+        #[arg(long)]
+        record: bool,
+    },
+    /// Time the parse step and each part of one day, or of every day
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+        /// Number of timed runs of every step
+        #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        iterations: u64,
+        /// Print the measurements as JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+    /// List the registered days
+    List,
+}
+
+#[derive(Args)]
+struct Selection {
+    day: Option<u8>,
+    /// Only run this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Read the input from this file, or from stdin with `-`
+    #[arg(short, long, requires = "day")]
+    input: Option<String>,
+    /// Directory holding one `day_XX.txt` input per day
+    #[arg(long, default_value = DEFAULT_INPUTS_DIR, conflicts_with = "input")]
+    inputs: PathBuf,
+}
+
+impl Selection {
+    fn days(&self) -> Result<Vec<&'static Day>, String> {
+        match self.day {
+            Some(number) => registry::find(number)
+                .map(|day| vec![day])
+                .ok_or_else(|| format!("No solver registered for day {}", number)),
+            None => Ok(registry::DAYS.iter().collect()),
+        }
+    }
+
+    fn source(&self) -> InputSource {
+        match &self.input {
+            Some(input) => InputSource::from_arg(Some(input)),
+            None => InputSource::Directory(self.inputs.clone()),
+        }
+    }
+
+    fn parts(&self) -> Vec<u8> {
+        self.part.map_or(vec![1, 2], |part| vec![part])
+    }
+
+    /// Calls `f` with the input of every selected day, reporting the days
+    /// that could not be loaded or solved.
+    fn for_each_day<T>(
+        &self,
+        mut f: impl FnMut(&Day, &str) -> Result<T, Box<dyn Error>>,
+        mut on_success: impl FnMut(&Day, T),
+    ) -> ExitCode {
+        let days = match self.days() {
+            Ok(days) => days,
+            Err(e) => {
+                eprintln!("{}", e);
+                return ExitCode::FAILURE;
+            }
+        };
+        let source = self.source();
+        let mut status = ExitCode::SUCCESS;
+        for day in days {
+            let result = source
+                .load(day.number)
+                .map_err(Into::<Box<dyn Error>>::into)
+                .and_then(|input| f(day, &input));
+            match result {
+                Ok(value) => on_success(day, value),
+                Err(e) => {
+                    eprintln!("Day {:02} - Error: {}", day.number, e);
+                    status = ExitCode::FAILURE;
+                }
+            }
+        }
+        status
+    }
+
+    /// Solves every selected day and part, parsing each input only once.
+    fn solve(
+        &self,
+        mut on_parse: impl FnMut(&Day, Duration),
+        mut on_answer: impl FnMut(&Day, &PartAnswer),
+    ) -> ExitCode {
+        let parts = self.parts();
+        self.for_each_day(
+            |day, input| day.run(input, &parts),
+            |day, solved| {
+                on_parse(day, solved.parse_time);
+                for answer in &solved.answers {
+                    on_answer(day, answer);
+                }
+            },
+        )
+    }
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().command {
+        Command::Run { selection, time } => selection.solve(
+            |day, parse_time| {
+                if time {
+                    println!("Day {:02} - Parse ({:.1?})", day.number, parse_time);
+                }
+            },
+            |day, solved| {
+                if time {
+                    println!(
+                        "Day {:02} - Part {}: {} ({:.1?})",
+                        day.number, solved.part, solved.answer, solved.time
+                    );
+                } else {
+                    println!(
+                        "Day {:02} - Part {}: {}",
+                        day.number, solved.part, solved.answer
+                    );
+                }
+            },
+        ),
+        Command::Verify {
+            selection,
+            answers: path,
+            record,
+        } => {
+            let mut answers = match Answers::load(&path) {
+                Ok(answers) => answers,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+            let mut failed = false;
+            let mut recorded = false;
+            let status = selection.solve(
+                |_, _| (),
+                |day, solved| {
+                    let (part, answer) = (solved.part, solved.answer.as_str());
+                    let verdict = match answers.check(day.number, part, answer) {
+                        Verdict::Pass => "pass".to_string(),
+                        Verdict::Fail { expected } => {
+                            failed = true;
+                            format!("FAIL (expected {})", expected)
+                        }
+                        Verdict::Unknown if record => {
+                            answers.record(day.number, part, answer);
+                            recorded = true;
+                            "recorded".to_string()
+                        }
+                        Verdict::Unknown => "unknown".to_string(),
+                    };
+                    println!(
+                        "Day {:02} - Part {}: {} {}",
+                        day.number, part, answer, verdict
+                    );
+                },
+            );
+            if recorded {
+                if let Err(e) = answers.save(&path) {
+                    eprintln!("{}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+            if failed {
+                ExitCode::FAILURE
+            } else {
+                status
+            }
+        }
+        Command::Bench {
+            selection,
+            iterations,
+            json,
+        } => {
+            let parts = selection.parts();
+            let mut measurements = vec![];
+            let status = selection.for_each_day(
+                |day, input| day.bench(input, &parts, iterations as usize),
+                |_, day_measurements| {
+                    for m in &day_measurements {
+                        if !json {
+                            println!(
+                                "Day {:02} - {:<6}  min {:>10.1?}  median {:>10.1?}  mean {:>10.1?}",
+                                m.day,
+                                m.step.name(),
+                                m.min,
+                                m.median,
+                                m.mean
+                            );
+                        }
+                    }
+                    measurements.extend(day_measurements);
+                },
+            );
+            if json {
+                match serde_json::to_string_pretty(&measurements) {
+                    Ok(json) => println!("{}", json),
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+            status
+        }
+        Command::List => {
+            for day in registry::DAYS {
+                println!("Day {:02}", day.number);
+            }
+            ExitCode::SUCCESS
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::{
+    error::Error,
+    time::{Duration, Instant},
+};
+
+use aoc_common::Solution;
+
+use crate::bench::{self, Measurement};
+
+type Answers = Result<Solved, Box<dyn Error>>;
+type Measurements = Result<Vec<Measurement>, Box<dyn Error>>;
+
+/// Answers of one day, with the time spent parsing and solving each part.
+pub struct Solved {
+    pub parse_time: Duration,
+    pub answers: Vec<PartAnswer>,
+}
+
+pub struct PartAnswer {
+    pub part: u8,
+    pub answer: String,
+    pub time: Duration,
+}
+
+/// A solver registered for one day of the calendar.
+pub struct Day {
+    pub number: u8,
+    run: fn(&str, &[u8]) -> Answers,
+    bench: fn(&str, &[u8], usize) -> Measurements,
+}
+
+impl Day {
+    const fn new<S: Solution>() -> Self {
+        Day {
+            number: S::DAY,
+            run: run::<S>,
+            bench: bench::bench::<S>,
+        }
+    }
+
+    /// Parses `input` once and solves every requested part from it.
+    pub fn run(&self, input: &str, parts: &[u8]) -> Answers {
+        (self.run)(input, parts)
+    }
+
+    /// Times the parse step and every requested part over `iterations` runs.
+    pub fn bench(&self, input: &str, parts: &[u8], iterations: usize) -> Measurements {
+        (self.bench)(input, parts, iterations)
+    }
+}
+
+fn run<S: Solution>(input: &str, parts: &[u8]) -> Answers {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&parsed)?.to_string(),
+                2 => S::part2(&parsed)?.to_string(),
+                _ => unreachable!("part is validated by the CLI"),
+            };
+            Ok(PartAnswer {
+                part,
+                answer,
+                time: start.elapsed(),
+            })
+        })
+        .collect::<Result<_, Box<dyn Error>>>()?;
+    Ok(Solved {
+        parse_time,
+        answers,
+    })
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day_01::Day01>(),
+    Day::new::<day_02::Day02>(),
+    Day::new::<day_03::Day03>(),
+    Day::new::<day_04::Day04>(),
+    Day::new::<day_05::Day05>(),
+    Day::new::<day_06::Day06>(),
+    Day::new::<day_07::Day07>(),
+    Day::new::<day_08::Day08>(),
+    Day::new::<day_09::Day09>(),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_sorted_and_unique() {
+        assert!(DAYS.windows(2).all(|w| w[0].number < w[1].number));
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(5).map(|day| day.number), Some(5));
+        assert!(find(25).is_none());
+    }
+}
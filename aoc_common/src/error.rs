@@ -0,0 +1,98 @@
+use std::fmt;
+
+/// Position in the puzzle input, both line and column starting at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    pub fn new(line: usize, column: usize) -> Self {
+        Location { line, column }
+    }
+
+    /// Location of `part` inside `input`, `part` being a subslice of `input`
+    /// (a line, a token, or the remaining input of a parser).
+    pub fn of(input: &str, part: &str) -> Self {
+        let offset = (part.as_ptr() as usize).saturating_sub(input.as_ptr() as usize);
+        let before = &input[..offset.min(input.len())];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Location {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+
+    /// Turns a location relative to a substring starting at `origin` into a
+    /// location relative to the whole input.
+    pub fn within(self, origin: Location) -> Self {
+        if self.line == 1 {
+            Location {
+                line: origin.line,
+                column: origin.column + self.column - 1,
+            }
+        } else {
+            Location {
+                line: origin.line + self.line - 1,
+                column: self.column,
+            }
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Location and offending text (the rest of the line, leading whitespace
+/// skipped) of a parser that stopped at `remaining`.
+pub fn stopped_at(input: &str, remaining: &str) -> (Location, String) {
+    let remaining = remaining.trim_start();
+    let text = remaining.lines().next().unwrap_or("");
+    (Location::of(input, remaining), text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location_of() {
+        let input = "Time:      7  15   30\nDistance:  9  40  200";
+        assert_eq!(Location::of(input, input), Location::new(1, 1));
+        assert_eq!(Location::of(input, &input[11..]), Location::new(1, 12));
+        assert_eq!(Location::of(input, &input[22..]), Location::new(2, 1));
+        assert_eq!(
+            Location::of(input, &input[input.len()..]),
+            Location::new(2, 22)
+        );
+    }
+
+    #[test]
+    fn test_location_within() {
+        let origin = Location::new(3, 9);
+        assert_eq!(Location::new(1, 1).within(origin), Location::new(3, 9));
+        assert_eq!(Location::new(1, 4).within(origin), Location::new(3, 12));
+        assert_eq!(Location::new(2, 4).within(origin), Location::new(4, 4));
+    }
+
+    #[test]
+    fn test_stopped_at() {
+        let input = "Game 1: 3 blue\nGame 2:  12 red\nGame 3";
+        assert_eq!(
+            stopped_at(input, &input[22..]),
+            (Location::new(2, 10), "12 red".to_string())
+        );
+        assert_eq!(
+            stopped_at(input, &input[14..]),
+            (Location::new(2, 1), "Game 2:  12 red".to_string())
+        );
+        assert_eq!(
+            stopped_at(input, &input[input.len()..]),
+            (Location::new(3, 7), String::new())
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Example inputs with their expected answers, turned into one test each.
+//!
+//! A fixture is a pair of files in the `fixtures` directory of a day crate:
+//! `<name>.txt` holds the input and `<name>.toml` the expected answers, e.g.
+//!
+//! ```toml
+//! part1 = 4361
+//! part2 = 467835
+//! ```
+//!
+//! A part without an expected answer (an example only meant for the other
+//! part) is not computed. The day's `build.rs` calls [`generate_tests`], and
+//! its `lib.rs` includes the generated tests:
+//!
+//! ```ignore
+//! #[cfg(test)]
+//! mod fixtures {
+//!     include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+//! }
+//! ```
+
+use std::{env, fmt::Write, fs, path::Path};
+
+use crate::Solution;
+
+/// Directory holding the fixtures, relative to the day crate.
+pub const FIXTURES_DIR: &str = "fixtures";
+
+// "day_03" -> "Day03"
+fn solution_name(package: &str) -> String {
+    format!("Day{}", package.trim_start_matches("day_"))
+}
+
+// "example-2" -> "example_2", "2" -> "fixture_2"
+fn test_name(fixture: &str) -> String {
+    let name = fixture
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("fixture_{name}")
+    } else {
+        name
+    }
+}
+
+/// To be called from the `build.rs` of a day crate: writes
+/// `$OUT_DIR/fixtures.rs`, with one test per `fixtures/<name>.txt`.
+pub fn generate_tests() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is not set");
+    let package = env::var("CARGO_PKG_NAME").expect("CARGO_PKG_NAME is not set");
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is not set");
+    let dir = Path::new(&manifest_dir).join(FIXTURES_DIR);
+    println!("cargo:rerun-if-changed={}", dir.display());
+
+    let mut fixtures = fs::read_dir(&dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let path = entry.ok()?.path();
+                    let stem = path.file_stem()?.to_str()?;
+                    (path.extension()? == "txt").then(|| stem.to_string())
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    fixtures.sort();
+
+    let mut code = String::new();
+    for fixture in fixtures {
+        writeln!(
+            code,
+            "#[test]\nfn {}() {{\n    aoc_common::fixtures::check::<crate::{}>(\n        \
+             concat!(env!(\"CARGO_MANIFEST_DIR\"), \"/{FIXTURES_DIR}\"),\n        {:?},\n    );\n}}\n",
+            test_name(&fixture),
+            solution_name(&package),
+            fixture,
+        )
+        .unwrap();
+    }
+    fs::write(Path::new(&out_dir).join("fixtures.rs"), code).expect("could not write fixtures.rs");
+}
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap_or_else(|e| panic!("could not read {}: {e}", path.display()))
+}
+
+/// Solves the fixture `name` of `dir` and compares the answers with the expected ones.
+pub fn check<S: Solution>(dir: &str, name: &str) {
+    let dir = Path::new(dir);
+    let input = read(&dir.join(format!("{name}.txt")));
+    let answers_path = dir.join(format!("{name}.toml"));
+    let expected = read(&answers_path)
+        .parse::<toml::Table>()
+        .unwrap_or_else(|e| panic!("invalid {}: {e}", answers_path.display()));
+    assert!(
+        !expected.is_empty(),
+        "{} has no expected answer",
+        answers_path.display()
+    );
+
+    let parsed = S::parse(&input).unwrap_or_else(|e| panic!("{name}: {e}"));
+    for (part, expected) in expected {
+        let answer = match part.as_str() {
+            "part1" => S::part1(&parsed).map(|answer| answer.to_string()),
+            "part2" => S::part2(&parsed).map(|answer| answer.to_string()),
+            _ => panic!(
+                "{}: unknown key {part:?}, expected part1 or part2",
+                answers_path.display()
+            ),
+        }
+        .unwrap_or_else(|e| panic!("{name} {part}: {e}"));
+        let expected = match expected {
+            toml::Value::String(s) => s,
+            value => value.to_string(),
+        };
+        assert_eq!(answer, expected, "{name} {part}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names() {
+        assert_eq!(solution_name("day_03"), "Day03");
+        assert_eq!(test_name("example"), "example");
+        assert_eq!(test_name("example-2.big"), "example_2_big");
+        assert_eq!(test_name("2"), "fixture_2");
+    }
+}
@@ -0,0 +1,183 @@
+use std::ops::Index;
+
+use crate::Location;
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum GridError {
+    #[error("{location}: expected a row of {expected} cells, found {found}")]
+    RaggedRow {
+        location: Location,
+        expected: usize,
+        found: usize,
+    },
+}
+
+/// Rectangular 2D grid, indexed by `(x, y)` with `(0, 0)` the top left cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+const OFFSETS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+impl<T> Grid<T> {
+    /// One row per line of `input`, one cell per character, converted with
+    /// `cell` which is given the character and its location in `input`.
+    pub fn parse<E: From<GridError>>(
+        input: &str,
+        mut cell: impl FnMut(char, Location) -> Result<T, E>,
+    ) -> Result<Self, E> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for (y, line) in input.lines().enumerate() {
+            let before = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                cells.push(cell(c, Location::new(y + 1, x + 1))?);
+            }
+            let found = cells.len() - before;
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(GridError::RaggedRow {
+                        location: Location::new(y + 1, 1),
+                        expected,
+                        found,
+                    }
+                    .into())
+                }
+                Some(_) => (),
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    /// Rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)`: `chunks` panics on 0, and an empty grid has no cells anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    /// Positions of the (up to 8) cells around `(x, y)`, diagonals included.
+    pub fn neighbours(&self, (x, y): (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        OFFSETS.iter().filter_map(move |&(dx, dy)| {
+            let nx = x.checked_add_signed(dx).filter(|&nx| nx < width)?;
+            let ny = y.checked_add_signed(dy).filter(|&ny| ny < height)?;
+            Some((nx, ny))
+        })
+    }
+
+    /// Positions of the (up to 4) cells above, left, right and below `(x, y)`.
+    pub fn orthogonal_neighbours(
+        &self,
+        (x, y): (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbours((x, y))
+            .filter(move |&(nx, ny)| nx == x || ny == y)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside the grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, GridError> {
+        Grid::parse(input, |c, _| Ok(c.to_digit(10).unwrap()))
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(0, 0)], 1);
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 2, 3][..], &[4, 5, 6][..]]
+        );
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &5)),);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            digits("123\n45\n789"),
+            Err(GridError::RaggedRow {
+                location: Location::new(2, 1),
+                expected: 3,
+                found: 2,
+            })
+        );
+        let locations = Grid::parse("12\n3x", |_, location| Ok::<_, GridError>(location));
+        assert_eq!(locations.unwrap()[(1, 1)], Location::new(2, 2));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits("123\n456\n789").unwrap();
+        let values = |positions: Vec<(usize, usize)>| {
+            positions.into_iter().map(|p| grid[p]).collect::<Vec<_>>()
+        };
+        assert_eq!(
+            values(grid.neighbours((1, 1)).collect()),
+            vec![1, 2, 3, 4, 6, 7, 8, 9]
+        );
+        assert_eq!(values(grid.neighbours((0, 0)).collect()), vec![2, 4, 5]);
+        assert_eq!(
+            values(grid.orthogonal_neighbours((1, 1)).collect()),
+            vec![2, 4, 6, 8]
+        );
+        assert_eq!(
+            values(grid.orthogonal_neighbours((2, 2)).collect()),
+            vec![6, 8]
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use std::{
+    fmt,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Directory searched for `day_XX.txt` when no explicit input is given.
+pub const DEFAULT_INPUTS_DIR: &str = "inputs";
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// An explicit file.
+    File(PathBuf),
+    /// The standard input, selected with `-`.
+    Stdin,
+    /// A directory holding one `day_XX.txt` file per day.
+    Directory(PathBuf),
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Directory(PathBuf::from(DEFAULT_INPUTS_DIR))
+    }
+}
+
+impl InputSource {
+    /// `None` -> default inputs directory, `-` -> stdin, anything else -> a file
+    pub fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            None => InputSource::default(),
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn load(&self, day: u8) -> Result<String, InputError> {
+        match self {
+            InputSource::File(path) => read_file(path, day),
+            InputSource::Directory(dir) => read_file(&dir.join(file_name(day)), day),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+// 5 -> "day_05.txt"
+pub fn file_name(day: u8) -> String {
+    format!("day_{:02}.txt", day)
+}
+
+fn read_file(path: &Path, day: u8) -> Result<String, InputError> {
+    std::fs::read_to_string(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound {
+            day,
+            path: path.to_path_buf(),
+        },
+        _ => InputError::Io {
+            path: path.to_path_buf(),
+            source: e,
+        },
+    })
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound { day: u8, path: PathBuf },
+    Io { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound { day, path } => write!(
+                f,
+                "No input for day {} at {} (pass a file path, `-` for stdin, or add {} to the inputs directory)",
+                day,
+                path.display(),
+                file_name(*day)
+            ),
+            InputError::Io { path, source } => {
+                write!(f, "Could not read {}: {}", path.display(), source)
+            }
+            InputError::Stdin(source) => write!(f, "Could not read stdin: {}", source),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::NotFound { .. } => None,
+            InputError::Io { source, .. } | InputError::Stdin(source) => Some(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(
+            InputSource::from_arg(None),
+            InputSource::Directory(PathBuf::from("inputs"))
+        );
+        assert_eq!(InputSource::from_arg(Some("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg(Some("my_input.txt")),
+            InputSource::File(PathBuf::from("my_input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(file_name(5), "day_05.txt");
+        assert_eq!(file_name(25), "day_25.txt");
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let source = InputSource::Directory(PathBuf::from("does/not/exist"));
+        let err = source.load(5).unwrap_err();
+        assert!(matches!(err, InputError::NotFound { day: 5, .. }));
+        assert!(err.to_string().contains("does/not/exist/day_05.txt"));
+    }
+}
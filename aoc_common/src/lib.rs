@@ -0,0 +1,14 @@
+pub mod error;
+pub mod fixtures;
+pub mod grid;
+pub mod input;
+pub mod math;
+pub mod parse;
+mod runner;
+mod solution;
+
+pub use error::Location;
+pub use grid::Grid;
+pub use input::InputSource;
+pub use runner::{example, main, report};
+pub use solution::Solution;
@@ -0,0 +1,82 @@
+pub fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Least common multiple, 0 if either number is 0.
+pub fn lcm(a: usize, b: usize) -> usize {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        a / gcd(a, b) * b
+    }
+}
+
+// (g, x, y) such that a * x + b * y = g = gcd(a, b)
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - a / b * y)
+    }
+}
+
+/// Chinese remainder theorem, generalized to moduli that are not pairwise coprime.
+///
+/// Given `(remainder, modulus)` congruences, returns `(x, m)` where `m` is the
+/// lcm of the moduli and `x < m` is the smallest number satisfying all of them
+/// (every solution being `x + k * m`). `None` if the congruences contradict
+/// each other or `m` does not fit in a `usize`.
+///
+/// # Panics
+///
+/// If a modulus is 0.
+pub fn crt(congruences: impl IntoIterator<Item = (usize, usize)>) -> Option<(usize, usize)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(r1, m1), (r2, m2)| {
+            assert!(m2 > 0, "modulus must be positive");
+            let (r1, m1, r2, m2) = (r1 as i128, m1 as i128, (r2 % m2) as i128, m2 as i128);
+            let (g, p, _) = extended_gcd(m1, m2);
+            if (r2 - r1) % g != 0 {
+                return None;
+            }
+            let m = usize::try_from((m1 / g).checked_mul(m2)?).ok()? as i128;
+            // m1 * k ≡ r2 - r1 (mod m2), p being the inverse of m1 / g modulo m2 / g;
+            // both factors are below 2^64, so their product fits in a u128
+            let n = m2 / g;
+            let k = ((r2 - r1) / g).rem_euclid(n) as u128 * p.rem_euclid(n) as u128 % n as u128;
+            let x = r1 + m1 * k as i128;
+            Some((x as usize, m as usize))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!([2, 3, 4].into_iter().fold(1, lcm), 12);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // not coprime
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        // remainder larger than its modulus
+        assert_eq!(crt([(7, 5)]), Some((2, 5)));
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(0, usize::MAX), (0, usize::MAX - 1)]), None);
+    }
+}
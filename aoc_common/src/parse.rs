@@ -0,0 +1,44 @@
+//! nom combinators shared by the days.
+
+use std::str::FromStr;
+
+use nom::{
+    character::complete::{digit1, space0, space1},
+    combinator::map_res,
+    multi::separated_list1,
+    sequence::preceded,
+    IResult,
+};
+
+/// An unsigned decimal number: `42`.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// At least one unsigned number, separated by spaces or tabs: `41 48  83`.
+pub fn numbers<T: FromStr>(input: &str) -> IResult<&str, Vec<T>> {
+    separated_list1(space1, unsigned)(input)
+}
+
+/// [`numbers`] after optional leading spaces or tabs: `  1 21`.
+pub fn padded_numbers<T: FromStr>(input: &str) -> IResult<&str, Vec<T>> {
+    preceded(space0, numbers)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(unsigned::<u8>("42 x"), Ok((" x", 42)));
+        assert!(unsigned::<u8>("256").is_err());
+        assert_eq!(
+            numbers::<usize>("41 48\t 83\n1"),
+            Ok(("\n1", vec![41, 48, 83]))
+        );
+        assert_eq!(numbers::<usize>("41 48 | 83"), Ok((" | 83", vec![41, 48])));
+        assert!(numbers::<usize>(" 41").is_err());
+        assert_eq!(padded_numbers::<usize>("  1 21"), Ok(("", vec![1, 21])));
+    }
+}
@@ -0,0 +1,54 @@
+use std::{error::Error, process::ExitCode};
+
+use crate::{InputSource, Solution};
+
+/// Entry point shared by the day binaries: `day_XX [PATH | -]`.
+///
+/// Without argument the input is read from `inputs/day_XX.txt`.
+pub fn main<S: Solution>() -> ExitCode {
+    report(run::<S>)
+}
+
+/// Entry point shared by the examples: `[PATH | -] [FLAG...]`.
+///
+/// `run` gets the input of day `S`, loaded as by [`main`], and the flags that
+/// were passed, any of `flags`. Other arguments starting with `--` are errors.
+pub fn example<S: Solution>(
+    flags: &[&str],
+    run: impl FnOnce(&str, &[String]) -> Result<(), Box<dyn Error>>,
+) -> ExitCode {
+    report(|| {
+        let (passed, paths): (Vec<_>, Vec<_>) = std::env::args()
+            .skip(1)
+            .partition(|arg| arg.starts_with("--"));
+        if let Some(unknown) = passed.iter().find(|flag| !flags.contains(&flag.as_str())) {
+            return Err(match flags {
+                [] => format!("unknown flag {unknown}, none expected"),
+                _ => format!("unknown flag {unknown}, expected {}", flags.join(" or ")),
+            }
+            .into());
+        }
+        let input = InputSource::from_arg(paths.first().map(String::as_str)).load(S::DAY)?;
+        run(&input, &passed)
+    })
+}
+
+/// Runs `run`, printing its error if any.
+pub fn report(run: impl FnOnce() -> Result<(), Box<dyn Error>>) -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run<S: Solution>() -> Result<(), Box<dyn Error>> {
+    let arg = std::env::args().nth(1);
+    let input = InputSource::from_arg(arg.as_deref()).load(S::DAY)?;
+    let parsed = S::parse(&input)?;
+    println!("Part 1: {}", S::part1(&parsed)?);
+    println!("Part 2: {}", S::part2(&parsed)?);
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use std::fmt::Display;
+
+/// A puzzle of one day: the input is parsed once, then both parts are
+/// computed from the same parsed value.
+pub trait Solution {
+    /// Day of the calendar, from 1 to 25.
+    const DAY: u8;
+
+    type Parsed;
+    type Answer1: Display;
+    type Answer2: Display;
+    type Error: std::error::Error + 'static;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Self::Error>;
+    fn part1(parsed: &Self::Parsed) -> Result<Self::Answer1, Self::Error>;
+    fn part2(parsed: &Self::Parsed) -> Result<Self::Answer2, Self::Error>;
+}
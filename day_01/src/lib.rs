@@ -0,0 +1,152 @@
+use aoc_common::{Location, Solution};
+
+pub struct Day01;
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    #[error("{location}: no digit in {text:?}")]
+    NoDigit { location: Location, text: String },
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Parsed = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(lines: &Self::Parsed) -> Result<usize, Error> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| to_two_digits(line).ok_or_else(|| no_digit(i, line)))
+            .sum()
+    }
+
+    fn part2(lines: &Self::Parsed) -> Result<usize, Error> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                to_two_digits(&letters_to_digits(line)).ok_or_else(|| no_digit(i, line))
+            })
+            .sum()
+    }
+}
+
+fn no_digit(line_idx: usize, line: &str) -> Error {
+    Error::NoDigit {
+        location: Location::new(line_idx + 1, 1),
+        text: line.to_string(),
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Day01::part1(&Day01::parse(input)?)
+}
+
+/// Calibration value of a line: its first and last digits, as a two-digit number.
+pub fn to_two_digits(line: &str) -> Option<usize> {
+    let first_digit = line.chars().find(|c| c.is_ascii_digit())?;
+    let last_digit = line.chars().rfind(|c| c.is_ascii_digit())?;
+    format!("{}{}", first_digit, last_digit)
+        .parse::<usize>()
+        .ok()
+}
+
+pub fn part2(input: &str) -> Result<usize, Error> {
+    Day01::part2(&Day01::parse(input)?)
+}
+
+const LETTERS_TO_DIGITS: [(&str, &str); 9] = [
+    ("one", "1"),
+    ("two", "2"),
+    ("three", "3"),
+    ("four", "4"),
+    ("five", "5"),
+    ("six", "6"),
+    ("seven", "7"),
+    ("eight", "8"),
+    ("nine", "9"),
+];
+
+/// Replaces the first and last spelled-out digits of a line by the digits themselves.
+pub fn letters_to_digits(line: &str) -> String {
+    change_last_letters_to_digits(&change_first_letters_to_digits(line))
+}
+
+fn change_first_letters_to_digits(line: &str) -> String {
+    let Some(first_char) = line.chars().next() else {
+        return String::new();
+    };
+    if first_char.is_ascii_digit() {
+        return line.to_string();
+    }
+
+    for (word, digit) in LETTERS_TO_DIGITS.iter() {
+        if line.starts_with(word) {
+            return format!("{}{}", digit, &line.strip_prefix(word).unwrap());
+        }
+    }
+    format!(
+        "{}{}",
+        &first_char,
+        change_first_letters_to_digits(&line[first_char.len_utf8()..])
+    )
+}
+
+fn change_last_letters_to_digits(line: &str) -> String {
+    let Some(last_char) = line.chars().last() else {
+        return String::new();
+    };
+    if last_char.is_ascii_digit() {
+        return line.to_string();
+    }
+
+    for (word, digit) in LETTERS_TO_DIGITS.iter() {
+        if line.ends_with(word) {
+            return format!("{}{}", &line.strip_suffix(word).unwrap(), digit);
+        }
+    }
+    format!(
+        "{}{}",
+        change_last_letters_to_digits(&line[..line.len() - last_char.len_utf8()]),
+        last_char
+    )
+}
+
+#[cfg(test)]
+mod fixtures {
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_letters_to_digits() {
+        assert_eq!(letters_to_digits("two1nine"), "219");
+        assert_eq!(letters_to_digits("eightwothree"), "8wo3");
+        assert_eq!(letters_to_digits("xtwone3four"), "x2ne34");
+        assert_eq!(letters_to_digits("zoneight234"), "z1ight234");
+    }
+
+    #[test]
+    fn test_no_digit() {
+        assert_eq!(
+            part1("1abc2\npqrstu"),
+            Err(Error::NoDigit {
+                location: Location::new(2, 1),
+                text: "pqrstu".to_string(),
+            })
+        );
+        assert_eq!(part2("1abc2\npqrstuone"), Ok(23));
+        assert!(part2("pqrstu").is_err());
+    }
+}
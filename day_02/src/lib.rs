@@ -0,0 +1,291 @@
+use aoc_common::{Location, Solution};
+use regex::Regex;
+use std::str::FromStr;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Parsed = Vec<Game>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = ParseGameError;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseGameError> {
+        input
+            .lines()
+            .map(|line| {
+                line.parse::<Game>()
+                    .map_err(|e| e.within(Location::of(input, line)))
+            })
+            .collect()
+    }
+
+    fn part1(games: &Self::Parsed) -> Result<usize, ParseGameError> {
+        let elf_set = Set {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+        Ok(games
+            .iter()
+            .filter(|game| game.is_possible(&elf_set))
+            .map(|game| game.id_number as usize)
+            .sum())
+    }
+
+    fn part2(games: &Self::Parsed) -> Result<usize, ParseGameError> {
+        Ok(games
+            .iter()
+            .map(|game| game.minimal_viable_set())
+            .map(|set| set.power())
+            .sum())
+    }
+}
+
+/// One game: its id and the sets of cubes revealed from the bag.
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    pub id_number: u8,
+    pub sets: Vec<Set>,
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum ParseGameError {
+    #[error("{location}: expected `Game <id>: <sets>`, found {text:?}")]
+    InvalidFormat { location: Location, text: String },
+    #[error("{location}: invalid game id {text:?}")]
+    InvalidId { location: Location, text: String },
+    #[error(transparent)]
+    InvalidSet(#[from] ParseSetError),
+}
+
+impl ParseGameError {
+    fn within(self, origin: Location) -> Self {
+        match self {
+            ParseGameError::InvalidFormat { location, text } => ParseGameError::InvalidFormat {
+                location: location.within(origin),
+                text,
+            },
+            ParseGameError::InvalidId { location, text } => ParseGameError::InvalidId {
+                location: location.within(origin),
+                text,
+            },
+            ParseGameError::InvalidSet(e) => ParseGameError::InvalidSet(e.within(origin)),
+        }
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseGameError;
+
+    // "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"
+    // -> Game { id_number: 1, sets: [Set {...}, Set {...}, Set {...}] }
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"^Game (?<id_number>\d+): (?<sets>.*)$").unwrap();
+        let caps = re
+            .captures(s)
+            .ok_or_else(|| ParseGameError::InvalidFormat {
+                location: Location::new(1, 1),
+                text: s.to_string(),
+            })?;
+        let id_number = &caps["id_number"];
+        let sets = caps["sets"]
+            .split("; ")
+            .map(|set| {
+                set.parse::<Set>()
+                    .map_err(|e| e.within(Location::of(s, set)))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Game {
+            id_number: id_number.parse().map_err(|_| ParseGameError::InvalidId {
+                location: Location::of(s, id_number),
+                text: id_number.to_string(),
+            })?,
+            sets,
+        })
+    }
+}
+
+impl Game {
+    /// Whether every revealed set fits in a bag holding `elf_set`.
+    pub fn is_possible(&self, elf_set: &Set) -> bool {
+        self.sets.iter().all(|set| set.is_possible(elf_set))
+    }
+
+    /// Fewest number of cubes of each color that could have been in the bag to make the game possible.
+    pub fn minimal_viable_set(&self) -> Set {
+        let mut red = 0;
+        let mut green = 0;
+        let mut blue = 0;
+
+        for set in &self.sets {
+            if set.red > red {
+                red = set.red;
+            }
+            if set.green > green {
+                green = set.green;
+            }
+            if set.blue > blue {
+                blue = set.blue;
+            }
+        }
+        Set { red, green, blue }
+    }
+}
+
+/// A number of cubes of each color.
+#[derive(Debug, PartialEq)]
+pub struct Set {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum ParseSetError {
+    #[error("{location}: expected `<number> <color>`, found {text:?}")]
+    InvalidCubes { location: Location, text: String },
+    #[error("{location}: invalid color {text:?}")]
+    InvalidColor { location: Location, text: String },
+}
+
+impl ParseSetError {
+    fn within(self, origin: Location) -> Self {
+        match self {
+            ParseSetError::InvalidCubes { location, text } => ParseSetError::InvalidCubes {
+                location: location.within(origin),
+                text,
+            },
+            ParseSetError::InvalidColor { location, text } => ParseSetError::InvalidColor {
+                location: location.within(origin),
+                text,
+            },
+        }
+    }
+}
+
+impl FromStr for Set {
+    type Err = ParseSetError;
+
+    // "1 red, 2 green, 6 blue" -> Set { red: 1, green: 2, blue: 6 }
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut red = 0;
+        let mut green = 0;
+        let mut blue = 0;
+
+        let re = Regex::new(r"^(?<number>\d+) (?<color>\S+)$").unwrap();
+        for sub_s in s.split(", ") {
+            let invalid_cubes = || ParseSetError::InvalidCubes {
+                location: Location::of(s, sub_s),
+                text: sub_s.to_string(),
+            };
+            let caps = re.captures(sub_s).ok_or_else(invalid_cubes)?;
+            let number = caps["number"].parse().map_err(|_| invalid_cubes())?;
+            match &caps["color"] {
+                "red" => red = number,
+                "green" => green = number,
+                "blue" => blue = number,
+                c => {
+                    return Err(ParseSetError::InvalidColor {
+                        location: Location::of(s, c),
+                        text: c.to_string(),
+                    })
+                }
+            }
+        }
+        Ok(Set { red, green, blue })
+    }
+}
+
+impl Set {
+    /// Whether this set fits in a bag holding `elf_set`.
+    pub fn is_possible(&self, elf_set: &Set) -> bool {
+        self.red <= elf_set.red && self.green <= elf_set.green && self.blue <= elf_set.blue
+    }
+
+    /// Product of the three counts.
+    pub fn power(&self) -> usize {
+        self.red as usize * self.green as usize * self.blue as usize
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, ParseGameError> {
+    Day02::part1(&Day02::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<usize, ParseGameError> {
+    Day02::part2(&Day02::parse(input)?)
+}
+
+#[cfg(test)]
+mod fixtures {
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        let game: Game = input.parse().unwrap();
+        assert_eq!(
+            game,
+            Game {
+                id_number: 1,
+                sets: vec![
+                    Set {
+                        red: 4,
+                        green: 0,
+                        blue: 3,
+                    },
+                    Set {
+                        red: 1,
+                        green: 2,
+                        blue: 6,
+                    },
+                    Set {
+                        red: 0,
+                        green: 2,
+                        blue: 0,
+                    },
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Day02::parse("Game 1: 3 blue\nGame 2: 3 blue, 4 purple"),
+            Err(ParseGameError::InvalidSet(ParseSetError::InvalidColor {
+                location: Location::new(2, 19),
+                text: "purple".to_string(),
+            }))
+        );
+        assert_eq!(
+            Day02::parse("Game 1: 3 blue; 4red"),
+            Err(ParseGameError::InvalidSet(ParseSetError::InvalidCubes {
+                location: Location::new(1, 17),
+                text: "4red".to_string(),
+            }))
+        );
+        assert_eq!(
+            Day02::parse("Game 1: 3 blue\nGame 300: 3 blue"),
+            Err(ParseGameError::InvalidId {
+                location: Location::new(2, 6),
+                text: "300".to_string(),
+            })
+        );
+        assert_eq!(
+            Day02::parse("Gam 1: 3 blue"),
+            Err(ParseGameError::InvalidFormat {
+                location: Location::new(1, 1),
+                text: "Gam 1: 3 blue".to_string(),
+            })
+        );
+    }
+}
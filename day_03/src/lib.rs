@@ -0,0 +1,215 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    str::FromStr,
+};
+
+use aoc_common::{
+    grid::{Grid, GridError},
+    Location, Solution,
+};
+
+pub struct Day03;
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    #[error("{location}: number {text} is too large")]
+    NumberTooLarge { location: Location, text: String },
+    #[error("{location}: invalid character {text:?}")]
+    InvalidCharacter { location: Location, text: String },
+    #[error(transparent)]
+    Grid(#[from] GridError),
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Parsed = Schematic;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Schematic::from_str(input)
+    }
+
+    fn part1(schematic: &Self::Parsed) -> Result<usize, Error> {
+        Ok(schematic
+            .numbers
+            .iter()
+            .filter(|n| schematic.adjacent_symbols(n).next().is_some())
+            .map(|n| n.value)
+            .sum())
+    }
+
+    fn part2(schematic: &Self::Parsed) -> Result<usize, Error> {
+        let mut gears: BTreeMap<(usize, usize), Vec<usize>> = BTreeMap::new();
+        for number in &schematic.numbers {
+            for (position, _) in schematic
+                .adjacent_symbols(number)
+                .filter(|&(_, symbol)| symbol == '*')
+            {
+                gears.entry(position).or_default().push(number.value);
+            }
+        }
+        Ok(gears
+            .values()
+            .filter(|numbers| numbers.len() == 2)
+            .map(|numbers| numbers[0] * numbers[1])
+            .sum())
+    }
+}
+
+/// A part number spanning columns `x1..=x2` of row `y` (0-based).
+#[derive(Debug, PartialEq, Eq)]
+pub struct Number {
+    pub value: usize,
+    pub y: usize,
+    pub x1: usize,
+    pub x2: usize,
+}
+
+/// The engine schematic: `.` for an empty cell, a digit, or a symbol.
+#[derive(Debug)]
+pub struct Schematic {
+    pub cells: Grid<char>,
+    pub numbers: Vec<Number>,
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+impl Schematic {
+    /// Symbols around `number`, diagonals included, each reported once.
+    pub fn adjacent_symbols<'a>(
+        &'a self,
+        number: &Number,
+    ) -> impl Iterator<Item = ((usize, usize), char)> + 'a {
+        (number.x1..=number.x2)
+            .flat_map(|x| self.cells.neighbours((x, number.y)))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(|position| (position, self.cells[position]))
+            .filter(|&(_, c)| is_symbol(c))
+    }
+}
+
+// consecutive digits of every row
+fn find_numbers(cells: &Grid<char>) -> Result<Vec<Number>, Error> {
+    let mut numbers = vec![];
+    for (y, row) in cells.rows().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            if !row[x].is_ascii_digit() {
+                x += 1;
+                continue;
+            }
+            let x1 = x;
+            while x < row.len() && row[x].is_ascii_digit() {
+                x += 1;
+            }
+            let text = row[x1..x].iter().collect::<String>();
+            numbers.push(Number {
+                value: text.parse().map_err(|_| Error::NumberTooLarge {
+                    location: Location::new(y + 1, x1 + 1),
+                    text,
+                })?,
+                y,
+                x1,
+                x2: x - 1,
+            });
+        }
+    }
+    Ok(numbers)
+}
+
+impl FromStr for Schematic {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let cells = Grid::parse(input, |c, location| {
+            if c.is_ascii_graphic() {
+                Ok(c)
+            } else {
+                Err(Error::InvalidCharacter {
+                    location,
+                    text: c.to_string(),
+                })
+            }
+        })?;
+        let numbers = find_numbers(&cells)?;
+        Ok(Schematic { cells, numbers })
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Day03::part1(&Day03::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<usize, Error> {
+    Day03::part2(&Day03::parse(input)?)
+}
+
+#[cfg(test)]
+mod fixtures {
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Day03::parse("467..114..\n...*...\t..").unwrap_err(),
+            Error::InvalidCharacter {
+                location: Location::new(2, 8),
+                text: "\t".to_string(),
+            }
+        );
+        assert_eq!(
+            Day03::parse("..123456789012345678901234567890*").unwrap_err(),
+            Error::NumberTooLarge {
+                location: Location::new(1, 3),
+                text: "123456789012345678901234567890".to_string(),
+            }
+        );
+        assert_eq!(
+            Day03::parse("467..\n...*").unwrap_err(),
+            Error::Grid(GridError::RaggedRow {
+                location: Location::new(2, 1),
+                expected: 5,
+                found: 4,
+            })
+        );
+    }
+
+    #[test]
+    fn test_adjacent_symbols() {
+        let schematic = Day03::parse("467..\n..*..\n.35#.").unwrap();
+        assert_eq!(
+            schematic.numbers,
+            vec![
+                Number {
+                    value: 467,
+                    y: 0,
+                    x1: 0,
+                    x2: 2
+                },
+                Number {
+                    value: 35,
+                    y: 2,
+                    x1: 1,
+                    x2: 2
+                },
+            ]
+        );
+        assert_eq!(
+            schematic
+                .adjacent_symbols(&schematic.numbers[1])
+                .collect::<Vec<_>>(),
+            vec![((2, 1), '*'), ((3, 2), '#')]
+        );
+    }
+}
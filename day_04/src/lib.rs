@@ -0,0 +1,206 @@
+use std::{collections::HashMap, str::FromStr};
+
+use aoc_common::{
+    error::stopped_at,
+    parse::{padded_numbers, unsigned},
+    Location, Solution,
+};
+use nom::{
+    bytes::complete::tag, character::complete::space1, sequence::separated_pair, Finish, IResult,
+};
+
+pub struct Day04;
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    #[error("{location}: unexpected {text:?}")]
+    Syntax { location: Location, text: String },
+    #[error("card {card}: too many points to count")]
+    PointsOverflow { card: usize },
+    #[error("card {card}: too many copies to count")]
+    CopiesOverflow { card: usize },
+}
+
+impl Error {
+    fn syntax(input: &str, remaining: &str) -> Self {
+        let (location, text) = stopped_at(input, remaining);
+        Error::Syntax { location, text }
+    }
+
+    fn within(self, origin: Location) -> Self {
+        match self {
+            Error::Syntax { location, text } => Error::Syntax {
+                location: location.within(origin),
+                text,
+            },
+            e @ (Error::PointsOverflow { .. } | Error::CopiesOverflow { .. }) => e,
+        }
+    }
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Parsed = Vec<Card>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        input
+            .lines()
+            .map(|line| Card::from_str(line).map_err(|e| e.within(Location::of(input, line))))
+            .collect()
+    }
+
+    fn part1(cards: &Self::Parsed) -> Result<usize, Error> {
+        cards.iter().try_fold(0usize, |total, card| {
+            total
+                .checked_add(card.points()?)
+                .ok_or(Error::PointsOverflow { card: card.id })
+        })
+    }
+
+    fn part2(cards: &Self::Parsed) -> Result<usize, Error> {
+        let overflow = |card: usize| Error::CopiesOverflow { card };
+        let mut occurences = HashMap::new();
+        for card in cards {
+            let copies = *occurences.entry(card.id).or_insert(1usize);
+            for number in 1..=card.matching_numbers().len() {
+                let won = card.id.checked_add(number).ok_or(overflow(card.id))?;
+                let occurence = occurences.entry(won).or_insert(1);
+                *occurence = occurence.checked_add(copies).ok_or(overflow(won))?;
+            }
+        }
+        occurences
+            .into_iter()
+            .try_fold(0usize, |total, (card, copies)| {
+                total.checked_add(copies).ok_or(overflow(card))
+            })
+    }
+}
+
+/// A scratchcard: the winning numbers on the left of `|`, the numbers I have on the right.
+#[derive(Debug)]
+pub struct Card {
+    pub id: usize,
+    pub winning_numbers: Vec<usize>,
+    pub my_numbers: Vec<usize>,
+}
+
+fn card_parser(input: &str) -> IResult<&str, Card> {
+    let (input, _) = tag("Card")(input)?;
+    let (input, _) = space1(input)?;
+    let (input, id) = unsigned(input)?;
+    let (input, _) = tag(":")(input)?;
+    let (input, (left, right)) = separated_pair(padded_numbers, tag(" | "), padded_numbers)(input)?;
+    Ok((
+        input,
+        Card {
+            id,
+            winning_numbers: left,
+            my_numbers: right,
+        },
+    ))
+}
+
+impl FromStr for Card {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match card_parser(s).finish() {
+            Ok(("", card)) => Ok(card),
+            Ok((remaining, _)) => Err(Error::syntax(s, remaining)),
+            Err(e) => Err(Error::syntax(s, e.input)),
+        }
+    }
+}
+
+impl Card {
+    /// My numbers that are also winning numbers.
+    pub fn matching_numbers(&self) -> Vec<usize> {
+        self.my_numbers
+            .iter()
+            .filter(|n| self.winning_numbers.contains(n))
+            .copied()
+            .collect()
+    }
+
+    /// 1 point for the first match, doubled for each further match.
+    pub fn points(&self) -> Result<usize, Error> {
+        match self.matching_numbers().len() {
+            0 => Ok(0),
+            matches => u32::try_from(matches - 1)
+                .ok()
+                .and_then(|exponent| 2_usize.checked_pow(exponent))
+                .ok_or(Error::PointsOverflow { card: self.id }),
+        }
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Day04::part1(&Day04::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<usize, Error> {
+    Day04::part2(&Day04::parse(input)?)
+}
+
+#[cfg(test)]
+mod fixtures {
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Day04::parse("Card 1: 41 48 | 83 86\nCard 2: 13 x2 | 61 30").unwrap_err(),
+            Error::Syntax {
+                location: Location::new(2, 12),
+                text: "x2 | 61 30".to_string(),
+            }
+        );
+        assert_eq!(
+            Day04::parse("Card 1: 41 48 | 83 86 !").unwrap_err(),
+            Error::Syntax {
+                location: Location::new(1, 23),
+                text: "!".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_points_overflow() {
+        let card = |matches: usize| Card {
+            id: 7,
+            winning_numbers: (0..matches).collect(),
+            my_numbers: (0..matches).collect(),
+        };
+        assert_eq!(card(0).points(), Ok(0));
+        assert_eq!(card(64).points(), Ok(1 << 63));
+        assert_eq!(card(65).points(), Err(Error::PointsOverflow { card: 7 }));
+        assert_eq!(
+            Day04::part1(&vec![card(64), card(64)]),
+            Err(Error::PointsOverflow { card: 7 })
+        );
+    }
+
+    #[test]
+    fn test_copies_overflow() {
+        let input = (1..=120)
+            .map(|i| format!("Card {i}: 1 2 | 1 2"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(matches!(part2(&input), Err(Error::CopiesOverflow { .. })));
+        // each card wins a copy of the next one, but the last
+        let input = (1..=120)
+            .map(|i| format!("Card {i}: 1 2 | {} 3", if i < 120 { 1 } else { 4 }))
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(part2(&input), Ok(120 * 121 / 2));
+    }
+}
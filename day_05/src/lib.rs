@@ -0,0 +1,802 @@
+use std::{ops::Range, str::FromStr};
+
+use aoc_common::{
+    error::stopped_at,
+    parse::{numbers, unsigned},
+    Location, Solution,
+};
+use nom::{
+    self,
+    bytes::complete::tag,
+    character::complete::{alpha1, newline, space1},
+    combinator::consumed,
+    error::ErrorKind,
+    multi::{many1, separated_list1},
+    sequence::{preceded, separated_pair, tuple},
+    Finish,
+};
+
+pub struct Day05;
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    #[error("{location}: unexpected {text:?}")]
+    Syntax { location: Location, text: String },
+    #[error("{location}: rule {text:?} goes past the largest number")]
+    RuleOverflow { location: Location, text: String },
+    #[error("seeds must come in (start, length) pairs, found {0} numbers")]
+    UnpairedSeeds(usize),
+    /// `pair` is numbered from 1.
+    #[error("seed range #{pair} ({start}, {length}) goes past the largest number")]
+    SeedRangeOverflow {
+        pair: usize,
+        start: usize,
+        length: usize,
+    },
+    #[error("no seed to plant")]
+    NoSeeds,
+    #[error("map is not invertible: several numbers are mapped to {0}")]
+    NotInvertible(usize),
+    #[error("cannot convert {from} to {to}: no map from {category}")]
+    BrokenChain {
+        from: String,
+        to: String,
+        category: String,
+    },
+    #[error("several maps from {0}")]
+    AmbiguousMaps(String),
+    #[error("maps form a cycle: {}", .0.join(" -> "))]
+    CyclicMaps(Vec<String>),
+    #[error("cannot apply a map from {second} after a map to {first}")]
+    MismatchedMaps { first: String, second: String },
+    /// `rules` are numbered from 1, in the order of the map.
+    #[error("{map} map: rules {rules:?} overlap on {}..{} and disagree", .range.start, .range.end)]
+    AmbiguousRules {
+        map: String,
+        range: Range<usize>,
+        rules: Vec<usize>,
+    },
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Parsed = TGrid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        TGrid::from_str(input)
+    }
+
+    fn part1(grid: &Self::Parsed) -> Result<usize, Error> {
+        let map = grid.compose()?;
+        grid.seeds
+            .iter()
+            .map(|&seed| map.convert(seed))
+            .min()
+            .ok_or(Error::NoSeeds)
+    }
+
+    fn part2(grid: &Self::Parsed) -> Result<usize, Error> {
+        grid.find_location_ranges(grid.seed_ranges()?)?
+            .iter()
+            .map(|range| range.start)
+            .min()
+            .ok_or(Error::NoSeeds)
+    }
+}
+
+/// One `<source>-to-<destination> map`, as `(source start, destination start, length)` rules.
+/// Numbers outside every rule map to themselves, and the first rule wins where rules overlap.
+#[derive(Debug)]
+pub struct TMap {
+    source: String,
+    destination: String,
+    rules: Vec<(usize, usize, usize)>,
+    // same mapping as `rules`, as disjoint rules sorted by source and
+    // without identity rules, for binary searches
+    segments: Vec<(usize, usize, usize)>,
+}
+
+fn parse_tmap(input: &str) -> nom::IResult<&str, TMap> {
+    let (input, (source, destination)) = separated_pair(alpha1, tag("-to-"), alpha1)(input)?;
+    let (input, _) = tag(" map:")(input)?;
+    let (input, _) = newline(input)?;
+    let (input, ranges) = separated_list1(
+        newline,
+        consumed(tuple((unsigned, space1, unsigned, space1, unsigned))),
+    )(input)?;
+    let ranges = ranges
+        .into_iter()
+        .map(
+            |(text, (d, _, s, _, r)): (_, (usize, _, usize, _, usize))| {
+                // both ends of the rule must be numbers themselves
+                match (s.checked_add(r), d.checked_add(r)) {
+                    (Some(_), Some(_)) => Ok((s, d, r)),
+                    _ => Err(nom::Err::Failure(nom::error::Error::new(
+                        text,
+                        ErrorKind::TooLarge,
+                    ))),
+                }
+            },
+        )
+        .collect::<Result<Vec<(usize, usize, usize)>, _>>()?;
+    Ok((input, TMap::new(source, destination, ranges)))
+}
+
+impl TMap {
+    /// Map from `source` to `destination` numbers following `rules`, see
+    /// [`TGrid::from_str`] to read maps and report their errors.
+    ///
+    /// # Panics
+    ///
+    /// If a rule goes past `usize::MAX`, at its source or its destination.
+    pub fn new(
+        source: impl Into<String>,
+        destination: impl Into<String>,
+        rules: Vec<(usize, usize, usize)>,
+    ) -> Self {
+        assert!(
+            rules
+                .iter()
+                .all(|&(s, d, r)| s.checked_add(r).is_some() && d.checked_add(r).is_some()),
+            "rules must end at most at usize::MAX"
+        );
+        let mut bounds = rules
+            .iter()
+            .flat_map(|&(s, _, r)| [s, s + r])
+            .collect::<Vec<_>>();
+        bounds.sort();
+        bounds.dedup();
+        // every interval between two bounds is mapped by a single rule, or none
+        let mut segments: Vec<(usize, usize, usize)> = vec![];
+        for w in bounds.windows(2) {
+            let (a, b) = (w[0], w[1]);
+            let Some(&(s, d, _)) = rules.iter().find(|&&(s, _, r)| (s..s + r).contains(&a)) else {
+                continue;
+            };
+            let d = d + (a - s);
+            match segments.last_mut() {
+                Some((ls, ld, lr)) if *ls + *lr == a && *ld + *lr == d => *lr += b - a,
+                _ => segments.push((a, d, b - a)),
+            }
+        }
+        segments.retain(|&(s, d, _)| s != d);
+        TMap {
+            source: source.into(),
+            destination: destination.into(),
+            rules,
+            segments,
+        }
+    }
+
+    /// Map converting a `category` number to itself.
+    pub fn identity(category: &str) -> Self {
+        TMap::new(category, category, vec![])
+    }
+
+    /// Category of the numbers to convert, e.g. `seed` for `seed-to-soil`.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Category of the converted numbers, e.g. `soil` for `seed-to-soil`.
+    pub fn destination(&self) -> &str {
+        &self.destination
+    }
+
+    /// The rules as written.
+    pub fn rules(&self) -> &[(usize, usize, usize)] {
+        &self.rules
+    }
+
+    /// The rules that actually apply: disjoint, sorted by source, identity rules left out.
+    pub fn segments(&self) -> &[(usize, usize, usize)] {
+        &self.segments
+    }
+
+    pub fn convert(&self, n: usize) -> usize {
+        let i = self.segments.partition_point(|&(s, _, _)| s <= n);
+        match i.checked_sub(1).map(|i| self.segments[i]) {
+            Some((s, d, r)) if n < s + r => d + (n - s),
+            _ => n,
+        }
+    }
+
+    /// Image of `range`: the parts covered by a rule are shifted, the others
+    /// kept as is. The output ranges are sorted by source, not merged.
+    pub fn convert_range(&self, range: Range<usize>) -> Vec<Range<usize>> {
+        let mut ranges = vec![];
+        let mut a = range.start;
+        let first = self.segments.partition_point(|&(s, _, r)| s + r <= a);
+        for &(s, d, r) in self.segments[first..]
+            .iter()
+            .take_while(|&&(s, _, _)| s < range.end)
+        {
+            if a < s {
+                ranges.push(a..s);
+                a = s;
+            }
+            let b = range.end.min(s + r);
+            ranges.push(d + (a - s)..d + (b - s));
+            a = b;
+        }
+        if a < range.end {
+            ranges.push(a..range.end);
+        }
+        ranges
+    }
+
+    // segments with the identity rules between them, covering `0..usize::MAX`
+    fn coverage(&self) -> Vec<(usize, usize, usize)> {
+        let mut coverage = vec![];
+        let mut next = 0;
+        for &(s, d, r) in &self.segments {
+            if next < s {
+                coverage.push((next, next, s - next));
+            }
+            coverage.push((s, d, r));
+            next = s + r;
+        }
+        if next < usize::MAX {
+            coverage.push((next, next, usize::MAX - next));
+        }
+        coverage
+    }
+
+    /// Single map applying `self` then `next`.
+    pub fn then(&self, next: &TMap) -> Result<TMap, Error> {
+        if self.destination != next.source {
+            return Err(Error::MismatchedMaps {
+                first: self.destination.clone(),
+                second: next.source.clone(),
+            });
+        }
+        let next_coverage = next.coverage();
+        let mut rules = vec![];
+        for (s, d, r) in self.coverage() {
+            let (mut a, end) = (d, d + r);
+            let mut i = next_coverage.partition_point(|&(s2, _, r2)| s2 + r2 <= a);
+            while a < end && i < next_coverage.len() {
+                let (s2, d2, r2) = next_coverage[i];
+                let b = end.min(s2 + r2);
+                rules.push((s + (a - d), d2 + (a - s2), b - a));
+                a = b;
+                i += 1;
+            }
+        }
+        rules.retain(|&(s, d, _)| s != d);
+        Ok(TMap::new(&self.source, &next.destination, rules))
+    }
+
+    /// Map from destination back to source, if no two numbers have the same image.
+    pub fn invert(&self) -> Result<TMap, Error> {
+        let mut coverage = self.coverage();
+        coverage.sort_by_key(|&(_, d, _)| d);
+        if let Some(w) = coverage.windows(2).find(|w| w[0].1 + w[0].2 > w[1].1) {
+            return Err(Error::NotInvertible(w[1].1));
+        }
+        Ok(TMap::new(
+            &self.destination,
+            &self.source,
+            coverage
+                .into_iter()
+                .filter(|&(s, d, _)| s != d)
+                .map(|(s, d, r)| (d, s, r))
+                .collect(),
+        ))
+    }
+
+    /// `<source>-to-<destination>`, as in the header of the map.
+    pub fn name(&self) -> String {
+        format!("{}-to-{}", self.source, self.destination)
+    }
+
+    /// Overlapping rules, gaps between rules and identity rules.
+    pub fn analyze(&self) -> Analysis {
+        let mut bounds = self
+            .rules
+            .iter()
+            .filter(|&&(_, _, r)| r > 0)
+            .flat_map(|&(s, _, r)| [s, s + r])
+            .collect::<Vec<_>>();
+        bounds.sort();
+        bounds.dedup();
+
+        let mut overlaps: Vec<Overlap> = vec![];
+        let mut gaps: Vec<Range<usize>> = vec![];
+        for w in bounds.windows(2) {
+            let range = w[0]..w[1];
+            let rules = (0..self.rules.len())
+                .filter(|&i| {
+                    let (s, _, r) = self.rules[i];
+                    s <= range.start && range.end <= s + r
+                })
+                .collect::<Vec<_>>();
+            match rules.len() {
+                0 => match gaps.last_mut() {
+                    Some(gap) if gap.end == range.start => gap.end = range.end,
+                    _ => gaps.push(range),
+                },
+                1 => (),
+                _ => match overlaps.last_mut() {
+                    Some(o) if o.range.end == range.start && o.rules == rules => {
+                        o.range.end = range.end
+                    }
+                    _ => overlaps.push(Overlap { range, rules }),
+                },
+            }
+        }
+        let identities = self
+            .rules
+            .iter()
+            .filter(|&&(s, d, r)| s == d && r > 0)
+            .map(|&(s, _, r)| s..s + r)
+            .collect();
+        Analysis {
+            overlaps,
+            gaps,
+            identities,
+        }
+    }
+}
+
+/// Report of [`TMap::analyze`], every range being a range of source numbers.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Analysis {
+    /// Ranges covered by several rules, where the first one wins.
+    pub overlaps: Vec<Overlap>,
+    /// Ranges between the rules that no rule covers, so mapped to themselves.
+    pub gaps: Vec<Range<usize>>,
+    /// Rules mapping numbers to themselves.
+    pub identities: Vec<Range<usize>>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Overlap {
+    pub range: Range<usize>,
+    /// Indices of the overlapping rules in [`TMap::rules`].
+    pub rules: Vec<usize>,
+}
+
+impl Overlap {
+    /// Whether the overlapping rules map `range` differently, the order of
+    /// the rules then mattering.
+    pub fn is_ambiguous(&self, map: &TMap) -> bool {
+        let offset = |i: usize| {
+            let (s, d, _) = map.rules()[i];
+            d as i128 - s as i128
+        };
+        self.rules
+            .iter()
+            .any(|&i| offset(i) != offset(self.rules[0]))
+    }
+}
+
+/// The almanac: seeds and the maps between categories, in any order, that
+/// lead from a seed to a location.
+#[derive(Debug)]
+pub struct TGrid {
+    pub seeds: Vec<usize>,
+    pub maps: Vec<TMap>,
+}
+
+fn parse_grid(input: &str) -> nom::IResult<&str, TGrid> {
+    let (input, seeds) = preceded(tag("seeds: "), numbers)(input)?;
+    let (input, maps) = many1(preceded(many1(newline), parse_tmap))(input)?;
+    Ok((input, TGrid { seeds, maps }))
+}
+
+impl FromStr for TGrid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let remaining = match parse_grid(s).finish() {
+            Ok((remaining, grid)) if remaining.trim().is_empty() => {
+                grid.validate()?;
+                return Ok(grid);
+            }
+            Ok((remaining, _)) => remaining,
+            Err(e) if e.code == ErrorKind::TooLarge => {
+                let (location, _) = stopped_at(s, e.input);
+                let text = e.input.lines().next().unwrap_or_default().to_string();
+                return Err(Error::RuleOverflow { location, text });
+            }
+            Err(e) => e.input,
+        };
+        let (location, text) = stopped_at(s, remaining);
+        Err(Error::Syntax { location, text })
+    }
+}
+
+// `visited` categories, `category` being one of them again
+fn cycle(visited: &[&str], category: &str) -> Error {
+    let start = visited.iter().position(|&c| c == category).unwrap_or(0);
+    let mut cycle = visited[start..]
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>();
+    cycle.push(category.to_string());
+    Error::CyclicMaps(cycle)
+}
+
+impl TGrid {
+    /// Maps to apply, in order, to convert a `from` number into a `to` number.
+    pub fn chain(&self, from: &str, to: &str) -> Result<Vec<&TMap>, Error> {
+        let mut chain = vec![];
+        let mut visited = vec![from];
+        let mut category = from;
+        while category != to {
+            let map = self.map_from(category)?.ok_or_else(|| Error::BrokenChain {
+                from: from.to_string(),
+                to: to.to_string(),
+                category: category.to_string(),
+            })?;
+            category = map.destination();
+            if visited.contains(&category) {
+                return Err(cycle(&visited, category));
+            }
+            visited.push(category);
+            chain.push(map);
+        }
+        Ok(chain)
+    }
+
+    // the map converting `category` numbers, if there is exactly one
+    fn map_from(&self, category: &str) -> Result<Option<&TMap>, Error> {
+        let mut maps = self.maps.iter().filter(|map| map.source() == category);
+        match (maps.next(), maps.next()) {
+            (_, Some(_)) => Err(Error::AmbiguousMaps(category.to_string())),
+            (map, None) => Ok(map),
+        }
+    }
+
+    /// Checks that every category converts to at most one other category,
+    /// without cycles.
+    pub fn validate(&self) -> Result<(), Error> {
+        for map in &self.maps {
+            let mut visited = vec![map.source()];
+            let mut category = map.source();
+            while let Some(next) = self.map_from(category)? {
+                category = next.destination();
+                if visited.contains(&category) {
+                    return Err(cycle(&visited, category));
+                }
+                visited.push(category);
+            }
+        }
+        Ok(())
+    }
+
+    /// Like [`TGrid::from_str`], also rejecting maps whose overlapping rules disagree.
+    pub fn parse_strict(input: &str) -> Result<Self, Error> {
+        let grid = TGrid::from_str(input)?;
+        for map in &grid.maps {
+            if let Some(overlap) = map
+                .analyze()
+                .overlaps
+                .into_iter()
+                .find(|overlap| overlap.is_ambiguous(map))
+            {
+                return Err(Error::AmbiguousRules {
+                    map: map.name(),
+                    range: overlap.range,
+                    rules: overlap.rules.iter().map(|i| i + 1).collect(),
+                });
+            }
+        }
+        Ok(grid)
+    }
+
+    /// Single map converting `from` numbers into `to` numbers.
+    pub fn conversion(&self, from: &str, to: &str) -> Result<TMap, Error> {
+        self.chain(from, to)?
+            .into_iter()
+            .try_fold(TMap::identity(from), |composed, map| composed.then(map))
+    }
+
+    /// All the maps composed into a single seed to location map.
+    pub fn compose(&self) -> Result<TMap, Error> {
+        self.conversion("seed", "location")
+    }
+
+    pub fn find_location(&self, seed: usize) -> Result<usize, Error> {
+        Ok(self
+            .chain("seed", "location")?
+            .iter()
+            .fold(seed, |seed, map| map.convert(seed)))
+    }
+
+    /// Seeds read as `(start, length)` pairs, as in part 2.
+    pub fn seed_ranges(&self) -> Result<Vec<Range<usize>>, Error> {
+        if !self.seeds.len().is_multiple_of(2) {
+            return Err(Error::UnpairedSeeds(self.seeds.len()));
+        }
+        self.seeds
+            .chunks(2)
+            .enumerate()
+            .map(|(i, c)| {
+                let end = c[0].checked_add(c[1]).ok_or(Error::SeedRangeOverflow {
+                    pair: i + 1,
+                    start: c[0],
+                    length: c[1],
+                })?;
+                Ok(c[0]..end)
+            })
+            .collect()
+    }
+
+    /// Locations of all the seeds of `seeds`, pushed through the maps as whole ranges.
+    pub fn find_location_ranges(
+        &self,
+        seeds: Vec<Range<usize>>,
+    ) -> Result<Vec<Range<usize>>, Error> {
+        Ok(self
+            .chain("seed", "location")?
+            .iter()
+            .fold(seeds, |ranges, map| {
+                ranges
+                    .into_iter()
+                    .flat_map(|range| map.convert_range(range))
+                    .collect()
+            }))
+    }
+
+    /// The seed planted to get `location`.
+    pub fn find_seed(&self, location: usize) -> Result<usize, Error> {
+        Ok(self.compose()?.invert()?.convert(location))
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Day05::part1(&Day05::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<usize, Error> {
+    Day05::part2(&Day05::parse(input)?)
+}
+
+#[cfg(test)]
+mod fixtures {
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_errors() {
+        let input = r#"seeds: 79 14 55
+
+seed-to-soil map:
+50 98 2
+52 50 -48"#;
+        assert_eq!(
+            Day05::parse(input).unwrap_err(),
+            Error::Syntax {
+                location: Location::new(5, 1),
+                text: "52 50 -48".to_string(),
+            }
+        );
+        let input = r#"seeds: 79 14 55
+
+seed-to-location map:
+50 98 2
+52 50 48"#;
+        assert_eq!(part1(input), Ok(14));
+        assert_eq!(part2(input), Err(Error::UnpairedSeeds(3)));
+    }
+
+    #[test]
+    fn test_overflows() {
+        let input = "seeds: 79 14\n\nseed-to-location map:\n50 98 2\n0 18446744073709551615 5";
+        assert_eq!(
+            Day05::parse(input).unwrap_err(),
+            Error::RuleOverflow {
+                location: Location::new(5, 1),
+                text: "0 18446744073709551615 5".to_string(),
+            }
+        );
+        let input = "seeds: 1 1\n\nseed-to-location map:\n18446744073709551615 0 2";
+        assert!(matches!(
+            Day05::parse(input),
+            Err(Error::RuleOverflow { .. })
+        ));
+        let input = "seeds: 1 1 18446744073709551615 2\n\nseed-to-location map:\n50 98 2";
+        assert_eq!(part1(input), Ok(1));
+        assert_eq!(
+            part2(input),
+            Err(Error::SeedRangeOverflow {
+                pair: 2,
+                start: usize::MAX,
+                length: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_convert_range() {
+        let map = TMap::new("seed", "soil", vec![(98, 50, 2), (50, 52, 48)]);
+        assert_eq!(map.convert_range(45..100), vec![45..50, 52..100, 50..52]);
+        assert_eq!(map.convert_range(60..70), vec![62..72]);
+        assert_eq!(map.convert_range(0..10), vec![0..10]);
+        assert_eq!(map.convert_range(10..10), vec![]);
+    }
+
+    #[test]
+    fn test_find_location_ranges() {
+        let grid = Day05::parse(include_str!("../fixtures/example.txt")).unwrap();
+        for seeds in grid.seed_ranges().unwrap() {
+            let locations = grid.find_location_ranges(vec![seeds.clone()]).unwrap();
+            let mut by_range = locations.into_iter().flatten().collect::<Vec<_>>();
+            let mut by_seed = seeds
+                .map(|seed| grid.find_location(seed).unwrap())
+                .collect::<Vec<_>>();
+            by_range.sort();
+            by_seed.sort();
+            assert_eq!(by_range, by_seed);
+        }
+    }
+
+    #[test]
+    fn test_segments() {
+        // overlapping, the first rule wins; `(10, 10, 5)` is the identity
+        let map = TMap::new(
+            "a",
+            "b",
+            vec![(0, 100, 10), (5, 200, 10), (10, 10, 5), (20, 30, 5)],
+        );
+        assert_eq!(map.segments(), &[(0, 100, 10), (10, 205, 5), (20, 30, 5)]);
+        for n in 0..40 {
+            let first_rule = map
+                .rules()
+                .iter()
+                .find(|&&(s, _, r)| (s..s + r).contains(&n))
+                .map_or(n, |&(s, d, _)| d + (n - s));
+            assert_eq!(map.convert(n), first_rule, "{n}");
+        }
+    }
+
+    #[test]
+    fn test_compose_and_invert() {
+        let grid = Day05::parse(include_str!("../fixtures/example.txt")).unwrap();
+        let composed = grid.compose().unwrap();
+        let inverse = composed.invert().unwrap();
+        assert_eq!(
+            (inverse.source(), inverse.destination()),
+            ("location", "seed")
+        );
+        for seed in 0..200 {
+            let location = grid.find_location(seed).unwrap();
+            assert_eq!(composed.convert(seed), location);
+            assert_eq!(inverse.convert(location), seed);
+        }
+        assert_eq!(grid.find_seed(35), Ok(13));
+        assert_eq!(
+            TMap::new("a", "b", vec![(0, 5, 10)]).invert().unwrap_err(),
+            Error::NotInvertible(10)
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        let input = include_str!("../fixtures/example.txt");
+        let grid = Day05::parse(input).unwrap();
+        let chain = grid.chain("water", "humidity").unwrap();
+        assert_eq!(
+            chain.iter().map(|map| map.source()).collect::<Vec<_>>(),
+            vec!["water", "light", "temperature"]
+        );
+        let water_to_humidity = grid.conversion("water", "humidity").unwrap();
+        for n in 0..120 {
+            let manual = chain.iter().fold(n, |n, map| map.convert(n));
+            assert_eq!(water_to_humidity.convert(n), manual);
+        }
+        assert_eq!(grid.conversion("soil", "soil").unwrap().segments(), &[]);
+        assert_eq!(
+            grid.conversion("location", "seed").unwrap_err(),
+            Error::BrokenChain {
+                from: "location".to_string(),
+                to: "seed".to_string(),
+                category: "location".to_string(),
+            }
+        );
+
+        // maps in any order
+        let (seeds, maps) = input.split_once("\n\n").unwrap();
+        let mut maps = maps.split("\n\n").collect::<Vec<_>>();
+        maps.reverse();
+        let shuffled = format!("{seeds}\n\n{}", maps.join("\n\n"));
+        assert_eq!(part1(&shuffled), Ok(35));
+        assert_eq!(part2(&shuffled), Ok(46));
+    }
+
+    #[test]
+    fn test_chain_errors() {
+        let almanac = |maps: &str| Day05::parse(&format!("seeds: 79 14\n\n{maps}"));
+        assert_eq!(
+            almanac("seed-to-soil map:\n1 2 3\n\nseed-to-water map:\n1 2 3").unwrap_err(),
+            Error::AmbiguousMaps("seed".to_string())
+        );
+        assert_eq!(
+            almanac("seed-to-soil map:\n1 2 3\n\nsoil-to-seed map:\n1 2 3").unwrap_err(),
+            Error::CyclicMaps(vec![
+                "seed".to_string(),
+                "soil".to_string(),
+                "seed".to_string()
+            ])
+        );
+        let grid = almanac("seed-to-soil map:\n1 2 3\n\nwater-to-location map:\n1 2 3").unwrap();
+        assert_eq!(
+            grid.compose().unwrap_err().to_string(),
+            "cannot convert seed to location: no map from soil"
+        );
+        assert_eq!(
+            grid.maps[0].then(&grid.maps[1]).unwrap_err(),
+            Error::MismatchedMaps {
+                first: "soil".to_string(),
+                second: "water".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_analyze() {
+        let map = TMap::new(
+            "a",
+            "b",
+            vec![
+                (10, 100, 10),
+                (15, 105, 10),
+                (30, 30, 5),
+                (18, 300, 2),
+                (40, 0, 5),
+                (50, 50, 1),
+            ],
+        );
+        assert_eq!(
+            map.analyze(),
+            Analysis {
+                overlaps: vec![
+                    Overlap {
+                        range: 15..18,
+                        rules: vec![0, 1],
+                    },
+                    Overlap {
+                        range: 18..20,
+                        rules: vec![0, 1, 3],
+                    },
+                ],
+                gaps: vec![25..30, 35..40, 45..50],
+                identities: vec![30..35, 50..51],
+            }
+        );
+        let overlaps = map.analyze().overlaps;
+        assert!(!overlaps[0].is_ambiguous(&map));
+        assert!(overlaps[1].is_ambiguous(&map));
+        assert_eq!(map.name(), "a-to-b");
+    }
+
+    #[test]
+    fn test_parse_strict() {
+        let input = include_str!("../fixtures/example.txt");
+        assert!(TGrid::parse_strict(input).is_ok());
+        let input = "seeds: 1 2\n\nseed-to-location map:\n100 10 10\n0 5 10";
+        assert!(TGrid::from_str(input).is_ok());
+        assert_eq!(
+            TGrid::parse_strict(input).unwrap_err(),
+            Error::AmbiguousRules {
+                map: "seed-to-location".to_string(),
+                range: 10..15,
+                rules: vec![1, 2],
+            }
+        );
+        assert_eq!(
+            TGrid::parse_strict(input).unwrap_err().to_string(),
+            "seed-to-location map: rules [1, 2] overlap on 10..15 and disagree"
+        );
+    }
+}
@@ -0,0 +1,14 @@
+//! Prints the strategy of every race: `cargo run -p day_06 --example explore [PATH | -]`
+
+use std::process::ExitCode;
+
+use aoc_common::Solution;
+use day_06::{explorer, Day06};
+
+fn main() -> ExitCode {
+    aoc_common::example::<Day06>(&[], |input, _| {
+        let sheet = Day06::parse(input)?;
+        print!("{}", explorer::table(&sheet.grid()?.explore()?));
+        Ok(())
+    })
+}
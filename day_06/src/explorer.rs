@@ -0,0 +1,203 @@
+//! What can be done in each race, beyond the number of ways to win.
+
+use std::{fmt::Write, ops::RangeInclusive};
+
+use crate::{model::RaceModel, Error};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    /// Duration of the race, in ms.
+    pub duration: u128,
+    /// Best distance so far, in mm.
+    pub record: u128,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Strategy {
+    pub race: Race,
+    /// Hold times beating the record, `None` if it cannot be beaten.
+    pub winning_holds: Option<RangeInclusive<u128>>,
+    /// Hold time going the farthest, the shortest one when two do.
+    pub optimal_hold: u128,
+    pub optimal_distance: u128,
+    /// How far beyond the record the optimal hold goes, 0 if it does not beat it.
+    pub margin: u128,
+}
+
+impl Strategy {
+    /// Number of hold times beating the record.
+    pub fn nb_of_ways_to_win(&self) -> u128 {
+        self.winning_holds
+            .as_ref()
+            .map_or(0, |holds| holds.end() - holds.start() + 1)
+    }
+}
+
+impl Race {
+    pub fn explore(&self) -> Result<Strategy, Error> {
+        self.explore_with(&RaceModel::default())
+    }
+
+    /// Strategy with boats moving as described by `model`.
+    pub fn explore_with(&self, model: &RaceModel) -> Result<Strategy, Error> {
+        let duration = self.duration;
+        let optimal_hold = model.optimal_hold(duration);
+        let optimal_distance = model
+            .distance(optimal_hold, duration)
+            .ok_or(Error::DistanceOverflow { duration })?;
+        Ok(Strategy {
+            race: *self,
+            winning_holds: model.winning_holds(duration, self.record),
+            optimal_hold,
+            optimal_distance,
+            margin: optimal_distance.saturating_sub(self.record),
+        })
+    }
+}
+
+/// One line per race, columns aligned:
+///
+/// ```text
+/// race  time  record  winning holds  ways  optimal hold  distance  margin
+///    1     7       9          2..=5     4             3        12       3
+/// ```
+pub fn table(strategies: &[Strategy]) -> String {
+    const HEADERS: [&str; 8] = [
+        "race",
+        "time",
+        "record",
+        "winning holds",
+        "ways",
+        "optimal hold",
+        "distance",
+        "margin",
+    ];
+    let rows = strategies
+        .iter()
+        .enumerate()
+        .map(|(i, strategy)| {
+            [
+                (i + 1).to_string(),
+                strategy.race.duration.to_string(),
+                strategy.race.record.to_string(),
+                strategy
+                    .winning_holds
+                    .as_ref()
+                    .map_or("-".to_string(), |holds| format!("{holds:?}")),
+                strategy.nb_of_ways_to_win().to_string(),
+                strategy.optimal_hold.to_string(),
+                strategy.optimal_distance.to_string(),
+                strategy.margin.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let widths = (0..HEADERS.len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].len())
+                .chain([HEADERS[column].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+
+    let mut table = String::new();
+    let headers = HEADERS.map(String::from);
+    for row in [&headers].into_iter().chain(&rows) {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:>width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(table, "{line}").unwrap();
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explore() {
+        let strategy = Race {
+            duration: 30,
+            record: 200,
+        }
+        .explore()
+        .unwrap();
+        assert_eq!(strategy.winning_holds, Some(11..=19));
+        assert_eq!(strategy.nb_of_ways_to_win(), 9);
+        assert_eq!(
+            (
+                strategy.optimal_hold,
+                strategy.optimal_distance,
+                strategy.margin
+            ),
+            (15, 225, 25)
+        );
+
+        let strategy = Race {
+            duration: 7,
+            record: 12,
+        }
+        .explore()
+        .unwrap();
+        assert_eq!(strategy.winning_holds, None);
+        assert_eq!(
+            (
+                strategy.optimal_hold,
+                strategy.optimal_distance,
+                strategy.margin
+            ),
+            (3, 12, 0)
+        );
+
+        let capped = RaceModel {
+            max_speed: Some(10),
+            ..RaceModel::default()
+        };
+        let strategy = Race {
+            duration: 30,
+            record: 189,
+        }
+        .explore_with(&capped)
+        .unwrap();
+        assert_eq!(strategy.winning_holds, Some(10..=11));
+        assert_eq!(
+            (
+                strategy.optimal_hold,
+                strategy.optimal_distance,
+                strategy.margin
+            ),
+            (10, 200, 11)
+        );
+
+        assert_eq!(
+            Race {
+                duration: u128::MAX,
+                record: 0
+            }
+            .explore(),
+            Err(Error::DistanceOverflow {
+                duration: u128::MAX
+            })
+        );
+    }
+
+    #[test]
+    fn test_table() {
+        let strategies = [(7, 9), (30, 200), (4, 4)]
+            .map(|(duration, record)| Race { duration, record }.explore().unwrap());
+        assert_eq!(
+            table(&strategies),
+            "\
+race  time  record  winning holds  ways  optimal hold  distance  margin
+   1     7       9          2..=5     4             3        12       3
+   2    30     200        11..=19     9            15       225      25
+   3     4       4              -     0             2         4       0
+"
+        );
+    }
+}
@@ -0,0 +1,391 @@
+pub mod explorer;
+pub mod model;
+mod wide;
+
+use std::str::FromStr;
+
+use aoc_common::{error::stopped_at, Location, Solution};
+use explorer::{Race, Strategy};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, newline, space0, space1},
+    combinator::map_opt,
+    multi::{many1, separated_list1},
+    sequence::preceded,
+    Finish, IResult,
+};
+use num_bigint::BigUint;
+use wide::U256;
+
+pub struct Day06;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    #[error("{location}: unexpected {text:?}")]
+    Syntax { location: Location, text: String },
+    #[error("{times} race times but {distances} record distances")]
+    MismatchedRaces { times: usize, distances: usize },
+    #[error("{text} does not fit in a u128")]
+    Overflow { text: String },
+    #[error("the best distance of a {duration} ms race does not fit in a u128")]
+    DistanceOverflow { duration: u128 },
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Parsed = Sheet;
+    type Answer1 = u128;
+    type Answer2 = u128;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Sheet::from_str(input)
+    }
+
+    fn part1(sheet: &Self::Parsed) -> Result<u128, Error> {
+        Ok(sheet
+            .grid()?
+            .races()
+            .map(|race| nb_of_ways_to_win(race.duration, race.record))
+            .product())
+    }
+
+    fn part2(sheet: &Self::Parsed) -> Result<u128, Error> {
+        let race = sheet.kerned()?.race()?;
+        Ok(nb_of_ways_to_win(race.duration, race.record))
+    }
+}
+
+/// Both readings of the sheet of paper: several races (part 1), or a single
+/// race once the spaces between digits are ignored (part 2).
+///
+/// Each reading keeps its own error, so that a sheet only readable one way
+/// still solves the matching part.
+#[derive(Debug)]
+pub struct Sheet {
+    grid: Result<Grid, Error>,
+    kerned: Result<KernedRace, Error>,
+}
+
+impl FromStr for Sheet {
+    type Err = Error;
+
+    /// Fails only if the sheet can be read neither way.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sheet = Sheet {
+            grid: s.parse(),
+            kerned: s.parse(),
+        };
+        match (&sheet.grid, &sheet.kerned) {
+            (Err(e), Err(_)) => Err(e.clone()),
+            _ => Ok(sheet),
+        }
+    }
+}
+
+impl Sheet {
+    pub fn grid(&self) -> Result<&Grid, Error> {
+        self.grid.as_ref().map_err(Clone::clone)
+    }
+
+    pub fn kerned(&self) -> Result<&KernedRace, Error> {
+        self.kerned.as_ref().map_err(Clone::clone)
+    }
+}
+
+// the parsed value if `input` was entirely read, or where the parser stopped
+fn finish<T>(input: &str, parsed: IResult<&str, T>) -> Result<T, Error> {
+    let remaining = match parsed.finish() {
+        Ok((remaining, value)) if remaining.trim().is_empty() => return Ok(value),
+        Ok((remaining, _)) => remaining,
+        Err(e) => e.input,
+    };
+    let (location, text) = stopped_at(input, remaining);
+    Err(Error::Syntax { location, text })
+}
+
+/// The races: `time[i]` is the duration of race `i` and `distance[i]` its record.
+#[derive(Debug)]
+pub struct Grid {
+    pub time: Vec<u128>,
+    pub distance: Vec<u128>,
+}
+
+impl FromStr for Grid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (time, distance) = finish(s, parse_grid(s))?;
+        if time.len() != distance.len() {
+            return Err(Error::MismatchedRaces {
+                times: time.len(),
+                distances: distance.len(),
+            });
+        }
+        // only digits, so only too large numbers fail
+        let small = |numbers: Vec<&str>| {
+            numbers
+                .into_iter()
+                .map(|n| {
+                    n.parse().map_err(|_| Error::Overflow {
+                        text: n.to_string(),
+                    })
+                })
+                .collect::<Result<Vec<u128>, _>>()
+        };
+        Ok(Grid {
+            time: small(time)?,
+            distance: small(distance)?,
+        })
+    }
+}
+
+impl Grid {
+    pub fn races(&self) -> impl Iterator<Item = Race> + '_ {
+        self.time
+            .iter()
+            .zip(&self.distance)
+            .map(|(&duration, &record)| Race { duration, record })
+    }
+
+    /// Strategy of every race, see [`explorer::table`] to display them.
+    pub fn explore(&self) -> Result<Vec<Strategy>, Error> {
+        self.races().map(|race| race.explore()).collect()
+    }
+}
+
+// the digits of each time and distance, converted once the lines are read
+fn parse_grid(input: &str) -> IResult<&str, (Vec<&str>, Vec<&str>)> {
+    let numbers = |input| separated_list1(space1, digit1)(input);
+    let (input, _) = tag("Time:")(input)?;
+    let (input, _) = space1(input)?;
+    let (input, time) = numbers(input)?;
+    let (input, _) = newline(input)?;
+    let (input, _) = tag("Distance:")(input)?;
+    let (input, _) = space1(input)?;
+    let (input, distance) = numbers(input)?;
+    Ok((input, (time, distance)))
+}
+
+/// The race of part 2, `7  15   30` reading as `71530`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct KernedRace {
+    pub time: BigUint,
+    pub distance: BigUint,
+}
+
+impl FromStr for KernedRace {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        finish(s, parse_kerned_race(s))
+    }
+}
+
+impl KernedRace {
+    pub fn race(&self) -> Result<Race, Error> {
+        let small = |n: &BigUint| {
+            u128::try_from(n).map_err(|_| Error::Overflow {
+                text: n.to_string(),
+            })
+        };
+        Ok(Race {
+            duration: small(&self.time)?,
+            record: small(&self.distance)?,
+        })
+    }
+}
+
+// all the digits of the line, whatever the spaces between them
+fn kerned_number(input: &str) -> IResult<&str, BigUint> {
+    map_opt(many1(preceded(space0, digit1)), |digits: Vec<&str>| {
+        BigUint::parse_bytes(digits.concat().as_bytes(), 10)
+    })(input)
+}
+
+fn parse_kerned_race(input: &str) -> IResult<&str, KernedRace> {
+    let (input, _) = tag("Time:")(input)?;
+    let (input, time) = kerned_number(input)?;
+    let (input, _) = newline(input)?;
+    let (input, _) = tag("Distance:")(input)?;
+    let (input, distance) = kerned_number(input)?;
+    Ok((input, KernedRace { time, distance }))
+}
+
+/// Number of hold times that beat `record_distance`.
+///
+/// Holding for `h` beats the record `D` of a race of duration `T` when
+/// `h * (T - h) > D`, i.e. `(T - 2h)^2 < T^2 - 4D`: the winning values of
+/// `T - 2h` are the ones of the same parity as `T` strictly inside
+/// `±sqrt(T^2 - 4D)`, which only needs an exact integer square root.
+pub fn nb_of_ways_to_win(race_duration: u128, record_distance: u128) -> u128 {
+    let square = U256::mul(race_duration, race_duration);
+    let four_times_record = U256::from(record_distance) << 2;
+    if square <= four_times_record {
+        return 0;
+    }
+    let discriminant = square - four_times_record;
+    let root = discriminant.isqrt();
+    // largest |T - 2h| allowed, the inequality being strict
+    let largest = if U256::mul(root, root) == discriminant {
+        root - 1
+    } else {
+        root
+    };
+    // same parity as T, from -largest to largest
+    let largest = if (race_duration - largest).is_multiple_of(2) {
+        largest
+    } else {
+        match largest.checked_sub(1) {
+            Some(largest) => largest,
+            None => return 0,
+        }
+    };
+    largest + 1
+}
+
+pub fn part1(input: &str) -> Result<u128, Error> {
+    Day06::part1(&Day06::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<u128, Error> {
+    Day06::part2(&Day06::parse(input)?)
+}
+
+#[cfg(test)]
+mod fixtures {
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input = r#"Time:      7  15   30
+Distance:  9  40  200"#;
+        let grid = Grid::from_str(input).unwrap();
+        assert_eq!(grid.time, vec![7, 15, 30]);
+        assert_eq!(grid.distance, vec![9, 40, 200]);
+
+        let kerned = KernedRace::from_str(input).unwrap();
+        assert_eq!(kerned.time, BigUint::from(71530u32));
+        assert_eq!(kerned.distance, BigUint::from(940200u32));
+        // zeros at the start of a number are digits of the kerned one
+        let kerned = KernedRace::from_str("Time: 7 05\nDistance: 9\t0 01").unwrap();
+        assert_eq!(
+            kerned.race().unwrap(),
+            Race {
+                duration: 705,
+                record: 9001
+            }
+        );
+        let kerned =
+            KernedRace::from_str("Time: 123456789012345678901234567890 1234567890\nDistance: 1")
+                .unwrap();
+        assert_eq!(
+            kerned.time.to_string(),
+            "1234567890123456789012345678901234567890"
+        );
+        assert_eq!(
+            kerned.race().unwrap_err().to_string(),
+            "1234567890123456789012345678901234567890 does not fit in a u128"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Grid::from_str("Time:      7  15   30\nDistance:  9  4O  200").unwrap_err(),
+            Error::Syntax {
+                location: Location::new(2, 16),
+                text: "O  200".to_string(),
+            }
+        );
+        assert_eq!(
+            Grid::from_str("Time:      7  15   30\nDistance:  9  40").unwrap_err(),
+            Error::MismatchedRaces {
+                times: 3,
+                distances: 2
+            }
+        );
+        assert_eq!(
+            part2("Time: 7 15 30 1\nDistance: 99999999999999999999 99999999999999999999 1"),
+            Err(Error::Overflow {
+                text: "99999999999999999999999999999999999999991".to_string()
+            })
+        );
+        // the kerned race does not care how many numbers are on each line
+        let input = "Time: 7 15 30\nDistance: 9 40200";
+        assert_eq!(part2(input), Ok(71503));
+        assert_eq!(
+            part1(input),
+            Err(Error::MismatchedRaces {
+                times: 3,
+                distances: 2
+            })
+        );
+        assert!(Sheet::from_str("Time: 7\nDistance: x").is_err());
+        // each race of the grid is read as a u128
+        let input = "Time: 99999999999999999999999\nDistance: 1";
+        assert_eq!(part1(input), part2(input));
+        assert_eq!(part1(input), Ok(99999999999999999999998));
+        assert_eq!(
+            part1("Time: 7 340282366920938463463374607431768211456\nDistance: 9 1"),
+            Err(Error::Overflow {
+                text: "340282366920938463463374607431768211456".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_nb_of_ways() {
+        assert_eq!(nb_of_ways_to_win(7, 9), 4);
+        assert_eq!(nb_of_ways_to_win(15, 40), 8);
+        assert_eq!(nb_of_ways_to_win(30, 200), 9);
+        assert_eq!(nb_of_ways_to_win(71530, 940200), 71503);
+        // 3 * 3 = 9 only equals the record
+        assert_eq!(nb_of_ways_to_win(6, 9), 0);
+        assert_eq!(nb_of_ways_to_win(6, 8), 1);
+        assert_eq!(nb_of_ways_to_win(0, 0), 0);
+        assert_eq!(nb_of_ways_to_win(u128::MAX, 0), u128::MAX - 1);
+        assert_eq!(nb_of_ways_to_win(u128::MAX, u128::MAX), u128::MAX - 3);
+    }
+
+    fn beats(hold_time: u128, race_duration: u128, record_distance: u128) -> bool {
+        hold_time
+            .checked_mul(race_duration - hold_time)
+            .is_none_or(|distance| distance > record_distance)
+    }
+
+    fn brute_force(race_duration: u128, record_distance: u128) -> u128 {
+        (0..=race_duration)
+            .filter(|&hold_time| beats(hold_time, race_duration, record_distance))
+            .count() as u128
+    }
+
+    proptest! {
+        #[test]
+        fn test_nb_of_ways_brute_force(race_duration in 0..2_000u128, record_distance in 0..1_000_000u128) {
+            prop_assert_eq!(
+                nb_of_ways_to_win(race_duration, record_distance),
+                brute_force(race_duration, record_distance)
+            );
+        }
+
+        // the winners are the hold times from `first` to `race_duration - first`
+        #[test]
+        fn test_nb_of_ways_bounds(race_duration: u128, record_distance: u128) {
+            let ways = nb_of_ways_to_win(race_duration, record_distance);
+            prop_assume!(ways > 0);
+            let first = (race_duration - (ways - 1)) / 2;
+            prop_assert_eq!(race_duration - first - first, ways - 1);
+            prop_assert!(beats(first, race_duration, record_distance));
+            prop_assert!(!beats(first - 1, race_duration, record_distance));
+        }
+    }
+}
@@ -0,0 +1,218 @@
+//! Boat physics beyond the puzzle's 1 mm/ms per ms held.
+
+use std::ops::RangeInclusive;
+
+use crate::nb_of_ways_to_win;
+
+/// How a boat moves: holding the button for `h` ms charges a speed of
+/// `charge_rate * h` mm/ms, capped at `max_speed`. Once released, the boat
+/// loses `deceleration` mm/ms every ms, until it stops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RaceModel {
+    pub charge_rate: u128,
+    pub max_speed: Option<u128>,
+    pub deceleration: Option<u128>,
+}
+
+impl Default for RaceModel {
+    /// The puzzle's boats.
+    fn default() -> Self {
+        RaceModel {
+            charge_rate: 1,
+            max_speed: None,
+            deceleration: None,
+        }
+    }
+}
+
+impl RaceModel {
+    pub fn speed(&self, hold_time: u128) -> u128 {
+        let speed = self.charge_rate.saturating_mul(hold_time);
+        self.max_speed
+            .map_or(speed, |max_speed| speed.min(max_speed))
+    }
+
+    /// Distance covered after holding for `hold_time` ms in a race of
+    /// `race_duration` ms, `None` if it does not fit in a `u128`.
+    pub fn distance(&self, hold_time: u128, race_duration: u128) -> Option<u128> {
+        let speed = self.speed(hold_time);
+        let remaining = race_duration.saturating_sub(hold_time);
+        match self.deceleration {
+            None | Some(0) => speed.checked_mul(remaining),
+            Some(deceleration) => {
+                // speeds `speed`, `speed - deceleration`, ... while positive
+                let moving = remaining.min(speed.div_ceil(deceleration));
+                let triangle = if moving % 2 == 0 {
+                    (moving / 2) * moving.saturating_sub(1)
+                } else {
+                    moving * (moving.saturating_sub(1) / 2)
+                };
+                // the lost distance is below `moving * speed` once that fits
+                Some(moving.checked_mul(speed)? - triangle * deceleration)
+            }
+        }
+    }
+
+    // distances too large for a u128 beat any record
+    fn beats(&self, hold_time: u128, race_duration: u128, record_distance: u128) -> bool {
+        self.distance(hold_time, race_duration)
+            .is_none_or(|distance| distance > record_distance)
+    }
+
+    /// Hold time going the farthest, the shortest one when several do.
+    ///
+    /// The distance increases with the hold time up to there, and never
+    /// increases after, whatever the model.
+    pub fn optimal_hold(&self, race_duration: u128) -> u128 {
+        let distance = |hold_time| self.distance(hold_time, race_duration);
+        // first hold time not improved by holding one more ms
+        first(0, race_duration, |hold_time| {
+            match (distance(hold_time), distance(hold_time + 1)) {
+                (None, _) => true,
+                (Some(_), None) => false,
+                (Some(d), Some(next)) => next <= d,
+            }
+        })
+    }
+
+    /// Hold times that beat `record_distance`, in a logarithmic number of
+    /// distance computations (constant time for the puzzle's model).
+    pub fn winning_holds(
+        &self,
+        race_duration: u128,
+        record_distance: u128,
+    ) -> Option<RangeInclusive<u128>> {
+        if self.max_speed.is_none() && self.deceleration.unwrap_or(0) == 0 {
+            // `rate * distance > record` when `distance > record / rate`,
+            // and the winners are symmetric around the middle of the race
+            let ways = match self.charge_rate {
+                0 => 0,
+                rate => nb_of_ways_to_win(race_duration, record_distance / rate),
+            };
+            let first_win = (race_duration - ways.checked_sub(1)?) / 2;
+            return Some(first_win..=race_duration - first_win);
+        }
+        let optimal_hold = self.optimal_hold(race_duration);
+        if !self.beats(optimal_hold, race_duration, record_distance) {
+            return None;
+        }
+        let first_win = first(0, optimal_hold, |hold_time| {
+            self.beats(hold_time, race_duration, record_distance)
+        });
+        let last_win = first(optimal_hold, race_duration, |hold_time| {
+            !self.beats(hold_time + 1, race_duration, record_distance)
+        });
+        Some(first_win..=last_win)
+    }
+
+    /// Number of hold times that beat `record_distance`.
+    pub fn nb_of_ways_to_win(&self, race_duration: u128, record_distance: u128) -> u128 {
+        self.winning_holds(race_duration, record_distance)
+            .map_or(0, |holds| holds.end() - holds.start() + 1)
+    }
+}
+
+// smallest `n` of `low..=high` such that `predicate(n)`, `predicate` being
+// false then true, `high` if it is never true before
+fn first(mut low: u128, mut high: u128, predicate: impl Fn(u128) -> bool) -> u128 {
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if predicate(middle) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn brute_force(model: &RaceModel, race_duration: u128, record_distance: u128) -> u128 {
+        (0..=race_duration)
+            .filter(|&hold_time| model.beats(hold_time, race_duration, record_distance))
+            .count() as u128
+    }
+
+    #[test]
+    fn test_distance() {
+        let model = RaceModel::default();
+        assert_eq!(
+            (0..=7).map(|h| model.distance(h, 7)).collect::<Vec<_>>(),
+            [0, 6, 10, 12, 12, 10, 6, 0].map(Some)
+        );
+        assert_eq!(model.distance(8, 7), Some(0));
+
+        let model = RaceModel {
+            charge_rate: 2,
+            max_speed: Some(5),
+            deceleration: None,
+        };
+        assert_eq!(model.speed(2), 4);
+        assert_eq!(model.speed(4), 5);
+        assert_eq!(model.distance(4, 10), Some(30));
+
+        let model = RaceModel {
+            charge_rate: 1,
+            max_speed: None,
+            deceleration: Some(2),
+        };
+        // 5 + 3 + 1, then stopped
+        assert_eq!(model.distance(5, 20), Some(9));
+        // 5 + 3 only
+        assert_eq!(model.distance(5, 7), Some(8));
+        assert_eq!(model.distance(0, 7), Some(0));
+
+        assert_eq!(
+            RaceModel::default().distance(u128::MAX / 2, u128::MAX),
+            None
+        );
+    }
+
+    #[test]
+    fn test_nb_of_ways() {
+        let model = RaceModel::default();
+        assert_eq!(model.nb_of_ways_to_win(30, 200), 9);
+        assert_eq!(model.winning_holds(30, 200), Some(11..=19));
+        assert_eq!(model.winning_holds(30, 225), None);
+        assert_eq!(model.optimal_hold(30), 15);
+        let model = RaceModel {
+            charge_rate: 3,
+            ..RaceModel::default()
+        };
+        assert_eq!(model.nb_of_ways_to_win(30, 600), 9);
+        assert_eq!(model.nb_of_ways_to_win(30, 626), 9);
+        assert_eq!(model.nb_of_ways_to_win(30, 627), 7);
+        let model = RaceModel {
+            charge_rate: 1,
+            max_speed: Some(10),
+            deceleration: None,
+        };
+        // 10 * (30 - h) from h = 10
+        assert_eq!(model.optimal_hold(30), 10);
+        assert_eq!(model.nb_of_ways_to_win(30, 199), 1);
+        assert_eq!(model.nb_of_ways_to_win(30, 189), 2);
+        assert_eq!(model.winning_holds(30, 189), Some(10..=11));
+    }
+
+    proptest! {
+        #[test]
+        fn test_nb_of_ways_brute_force(
+            charge_rate in 0..5u128,
+            max_speed in proptest::option::of(0..50u128),
+            deceleration in proptest::option::of(0..5u128),
+            race_duration in 0..300u128,
+            record_distance in 0..5_000u128,
+        ) {
+            let model = RaceModel { charge_rate, max_speed, deceleration };
+            prop_assert_eq!(
+                model.nb_of_ways_to_win(race_duration, record_distance),
+                brute_force(&model, race_duration, record_distance)
+            );
+        }
+    }
+}
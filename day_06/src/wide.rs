@@ -0,0 +1,144 @@
+//! Just enough 256-bit arithmetic for the discriminant of a race, whose
+//! duration squared does not fit in a `u128`.
+
+use std::ops::{Add, Shl, Shr, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct U256 {
+    hi: u128,
+    lo: u128,
+}
+
+impl U256 {
+    pub(crate) const ZERO: U256 = U256 { hi: 0, lo: 0 };
+
+    pub(crate) fn mul(a: u128, b: u128) -> Self {
+        const MASK: u128 = u64::MAX as u128;
+        let (a_hi, a_lo) = (a >> 64, a & MASK);
+        let (b_hi, b_lo) = (b >> 64, b & MASK);
+        let lo_lo = a_lo * b_lo;
+        let (middle, carry) = (a_hi * b_lo).overflowing_add(a_lo * b_hi);
+        let (lo, carry_lo) = lo_lo.overflowing_add(middle << 64);
+        U256 {
+            hi: a_hi * b_hi + (middle >> 64) + ((carry as u128) << 64) + carry_lo as u128,
+            lo,
+        }
+    }
+
+    /// Floor of the square root, digit by digit.
+    pub(crate) fn isqrt(self) -> u128 {
+        let mut n = self;
+        let mut result = U256::ZERO;
+        let mut bit = U256::from(1) << 254;
+        while bit > n {
+            bit = bit >> 2;
+        }
+        while bit != U256::ZERO {
+            if n >= result + bit {
+                n = n - (result + bit);
+                result = (result >> 1) + bit;
+            } else {
+                result = result >> 1;
+            }
+            bit = bit >> 2;
+        }
+        result.lo
+    }
+}
+
+impl From<u128> for U256 {
+    fn from(n: u128) -> Self {
+        U256 { hi: 0, lo: n }
+    }
+}
+
+impl Add for U256 {
+    type Output = U256;
+
+    fn add(self, other: U256) -> U256 {
+        let (lo, carry) = self.lo.overflowing_add(other.lo);
+        U256 {
+            hi: self.hi + other.hi + carry as u128,
+            lo,
+        }
+    }
+}
+
+impl Sub for U256 {
+    type Output = U256;
+
+    fn sub(self, other: U256) -> U256 {
+        let (lo, borrow) = self.lo.overflowing_sub(other.lo);
+        U256 {
+            hi: self.hi - other.hi - borrow as u128,
+            lo,
+        }
+    }
+}
+
+impl Shl<u32> for U256 {
+    type Output = U256;
+
+    fn shl(self, n: u32) -> U256 {
+        match n {
+            0 => self,
+            1..=127 => U256 {
+                hi: (self.hi << n) | (self.lo >> (128 - n)),
+                lo: self.lo << n,
+            },
+            _ => U256 {
+                hi: self.lo << (n - 128),
+                lo: 0,
+            },
+        }
+    }
+}
+
+impl Shr<u32> for U256 {
+    type Output = U256;
+
+    fn shr(self, n: u32) -> U256 {
+        match n {
+            0 => self,
+            1..=127 => U256 {
+                hi: self.hi >> n,
+                lo: (self.lo >> n) | (self.hi << (128 - n)),
+            },
+            _ => U256 {
+                hi: 0,
+                lo: self.hi >> (n - 128),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mul() {
+        assert_eq!(U256::mul(6, 7), U256::from(42));
+        assert_eq!(
+            U256::mul(u128::MAX, u128::MAX),
+            // (2^128 - 1)^2 = 2^256 - 2^129 + 1
+            U256 {
+                hi: u128::MAX - 1,
+                lo: 1
+            }
+        );
+        assert_eq!(U256::mul(1 << 64, 1 << 64), U256 { hi: 1, lo: 0 });
+    }
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(U256::ZERO.isqrt(), 0);
+        assert_eq!(U256::from(15).isqrt(), 3);
+        assert_eq!(U256::from(16).isqrt(), 4);
+        assert_eq!(U256::mul(u128::MAX, u128::MAX).isqrt(), u128::MAX);
+        assert_eq!(
+            (U256::mul(u128::MAX, u128::MAX) - U256::from(1)).isqrt(),
+            u128::MAX - 1
+        );
+    }
+}
@@ -0,0 +1,28 @@
+//! Prints every hand from the weakest to the strongest with why it ranks there:
+//! `cargo run -p day_07 --example explain [PATH | -] [--jacks]`
+
+use std::process::ExitCode;
+
+use aoc_common::Solution;
+use day_07::Day07;
+
+fn main() -> ExitCode {
+    aoc_common::example::<Day07>(&["--jacks"], |input, flags| {
+        let hands = Day07::parse(input)?;
+        let mut hands = match flags.iter().any(|flag| flag == "--jacks") {
+            true => hands.jacks,
+            false => hands.jokers,
+        };
+        hands.sort_by(|(h1, _), (h2, _)| h1.cmp(h2));
+        for (rank, (hand, bid)) in hands.iter().enumerate() {
+            println!(
+                "{:>5} {} {:>4}  {}",
+                rank + 1,
+                hand.cards(),
+                bid,
+                hand.evaluation()
+            );
+        }
+        Ok(())
+    })
+}
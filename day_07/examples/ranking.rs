@@ -0,0 +1,26 @@
+//! Prints every hand with its rank and winnings, as CSV or JSON:
+//! `cargo run -p day_07 --example ranking [PATH | -] [--jacks] [--json]`
+
+use std::process::ExitCode;
+
+use aoc_common::Solution;
+use day_07::{Day07, Ranking};
+
+fn main() -> ExitCode {
+    aoc_common::example::<Day07>(&["--jacks", "--json"], |input, flags| {
+        let flag = |name: &str| flags.iter().any(|flag| flag == name);
+        let hands = Day07::parse(input)?;
+        let ranking = Ranking::new(match flag("--jacks") {
+            true => &hands.jacks,
+            false => &hands.jokers,
+        });
+        match flag("--json") {
+            true => println!("{}", ranking.to_json()),
+            false => print!("{}", ranking.to_csv()),
+        }
+        for tied in ranking.ties() {
+            eprintln!("tied hands on lines {:?}", tied);
+        }
+        Ok(())
+    })
+}
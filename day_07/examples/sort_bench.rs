@@ -0,0 +1,78 @@
+//! Sorts generated hands by their precomputed key, and by re-reading both hands
+//! on every comparison as `Ord for Hand` used to:
+//! `cargo run --release -p day_07 --example sort_bench [NB_HANDS]`
+
+use std::{cmp::Ordering, error::Error, process::ExitCode, time::Instant};
+
+use day_07::{Hand, Rules};
+
+const DECK: &[u8] = b"23456789TJQKA";
+
+// xorshift64, good enough to deal cards
+fn hands(nb_hands: usize, rules: &Rules) -> Vec<Hand> {
+    let mut state = 0x2023_0712_u64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    (0..nb_hands)
+        .map(|_| {
+            let cards = (0..5)
+                .map(|_| DECK[(next() % DECK.len() as u64) as usize] as char)
+                .collect::<String>();
+            Hand::new(&cards, rules).unwrap()
+        })
+        .collect()
+}
+
+fn reread(h1: &Hand, h2: &Hand, rules: &Rules) -> Ordering {
+    let h1 = Hand::new(h1.cards(), rules).unwrap();
+    let h2 = Hand::new(h2.cards(), rules).unwrap();
+    h1.hand_type().cmp(h2.hand_type()).then_with(|| {
+        let strengths = |hand: &Hand| {
+            hand.cards()
+                .chars()
+                .map(|c| rules.card(c).unwrap().strength())
+                .collect::<Vec<_>>()
+        };
+        strengths(&h1).cmp(&strengths(&h2))
+    })
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let nb_hands = match std::env::args().nth(1) {
+        Some(arg) => arg.parse()?,
+        None => 100_000,
+    };
+    let rules = Rules::jokers();
+    let generated = hands(nb_hands, &rules);
+
+    let mut by_key = generated.clone();
+    let start = Instant::now();
+    by_key.sort_unstable_by_key(Hand::key);
+    let key_time = start.elapsed();
+
+    let mut reread_each_time = generated;
+    let start = Instant::now();
+    reread_each_time.sort_unstable_by(|h1, h2| reread(h1, h2, &rules));
+    let reread_time = start.elapsed();
+
+    assert!(by_key
+        .iter()
+        .zip(&reread_each_time)
+        .all(|(h1, h2)| h1.key() == h2.key()));
+    println!("{nb_hands} hands");
+    println!("by key:             {key_time:?}");
+    println!("re-reading hands:   {reread_time:?}");
+    println!(
+        "speedup:            x{:.1}",
+        reread_time.as_secs_f64() / key_time.as_secs_f64()
+    );
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    aoc_common::report(run)
+}
@@ -0,0 +1,157 @@
+//! Why a hand has its type and its rank among hands of the same type.
+
+use std::fmt;
+
+use crate::{hand::Card, rules::TieBreak, HandType};
+
+/// A wildcard of the hand and the card it counts as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Assignment {
+    /// 1-based position of the wildcard in the hand.
+    pub position: usize,
+    pub wildcard: Card,
+    pub counts_as: Card,
+}
+
+/// How a hand was classified.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Evaluation {
+    pub hand_type: HandType,
+    /// Each card of the hand with how many times it counts once wildcards are
+    /// assigned, largest group first.
+    pub groups: Vec<(Card, u8)>,
+    /// Wildcards counting as another card; one counting as itself is not listed.
+    pub assignments: Vec<Assignment>,
+    /// Card strengths compared, in order, between hands of the same type.
+    pub tie_break: Vec<u8>,
+}
+
+impl Evaluation {
+    /// Every wildcard joins the largest group (the strongest card among equally
+    /// large ones), or the strongest wildcard if the hand has nothing else.
+    pub(crate) fn new(cards: &[Card], policy: TieBreak) -> Self {
+        let mut groups: Vec<(Card, u8)> = vec![];
+        for &card in cards.iter().filter(|card| !card.is_wild()) {
+            match groups.iter_mut().find(|(c, _)| c.label() == card.label()) {
+                Some((_, count)) => *count += 1,
+                None => groups.push((card, 1)),
+            }
+        }
+        groups.sort_by(|(c1, n1), (c2, n2)| n2.cmp(n1).then(c2.cmp(c1)));
+
+        let wildcards = cards
+            .iter()
+            .enumerate()
+            .filter(|(_, card)| card.is_wild())
+            .collect::<Vec<_>>();
+        let mut assignments = vec![];
+        if let Some(&counts_as) = groups
+            .first()
+            .map(|(card, _)| card)
+            .or_else(|| wildcards.iter().map(|&(_, card)| card).max())
+        {
+            if groups.is_empty() {
+                groups.push((counts_as, 0));
+            }
+            for &(i, &wildcard) in &wildcards {
+                groups[0].1 += 1;
+                if wildcard.label() != counts_as.label() {
+                    assignments.push(Assignment {
+                        position: i + 1,
+                        wildcard,
+                        counts_as,
+                    });
+                }
+            }
+        }
+
+        let hand_type = HandType::new(groups.iter().map(|&(_, count)| count).collect::<Vec<_>>());
+
+        let mut tie_break = cards.iter().map(Card::strength).collect::<Vec<_>>();
+        if policy == TieBreak::HighestCard {
+            tie_break.sort_by(|s1, s2| s2.cmp(s1));
+        }
+
+        Evaluation {
+            hand_type,
+            groups,
+            assignments,
+            tie_break,
+        }
+    }
+}
+
+// "four of a kind: T×4 K×1, J#3 as T, J#4 as T, tie-break [11, 9, 0, 0, 9]"
+impl fmt::Display for Evaluation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:", self.hand_type)?;
+        for (card, count) in &self.groups {
+            write!(f, " {}×{}", card.label(), count)?;
+        }
+        for assignment in &self.assignments {
+            write!(
+                f,
+                ", {}#{} as {}",
+                assignment.wildcard.label(),
+                assignment.position,
+                assignment.counts_as.label()
+            )?;
+        }
+        write!(f, ", tie-break {:?}", self.tie_break)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Hand, Rules, TieBreak};
+
+    fn explain(s: &str, rules: &Rules) -> String {
+        Hand::new(s, rules).unwrap().evaluation().to_string()
+    }
+
+    #[test]
+    fn test_explanations() {
+        let rules = Rules::jokers();
+        assert_eq!(
+            explain("KTJJT", &rules),
+            "four of a kind: T×4 K×1, J#3 as T, J#4 as T, tie-break [11, 9, 0, 0, 9]"
+        );
+        assert_eq!(
+            explain("32T3K", &rules),
+            "one pair: 3×2 K×1 T×1 2×1, tie-break [2, 1, 9, 2, 11]"
+        );
+        assert_eq!(
+            explain("JJJJJ", &rules),
+            "five of a kind: J×5, tie-break [0, 0, 0, 0, 0]"
+        );
+        // equally large groups: the wildcard joins the strongest
+        assert_eq!(
+            explain("Q2J2Q", &rules),
+            "full house: Q×3 2×2, J#3 as Q, tie-break [10, 1, 0, 1, 10]"
+        );
+        assert_eq!(
+            explain("KTJJT", &Rules::jacks()),
+            "two pairs: J×2 T×2 K×1, tie-break [11, 8, 9, 9, 8]"
+        );
+    }
+
+    #[test]
+    fn test_several_wildcards() {
+        let rules = Rules::new("2J3456789TQKA", "2J", TieBreak::HighestCard).unwrap();
+        let evaluation = Hand::new("2JJ22", &rules).unwrap().evaluation().clone();
+        assert_eq!(evaluation.groups.len(), 1);
+        assert_eq!(evaluation.groups[0].0.label(), 'J');
+        assert_eq!(
+            evaluation
+                .assignments
+                .iter()
+                .map(|a| a.position)
+                .collect::<Vec<_>>(),
+            vec![1, 4, 5]
+        );
+        assert_eq!(
+            evaluation.to_string(),
+            "five of a kind: J×5, 2#1 as J, 2#4 as J, 2#5 as J, tie-break [1, 1, 0, 0, 0]"
+        );
+    }
+}
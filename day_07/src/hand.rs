@@ -0,0 +1,303 @@
+//! Camel Cards hands, under any [`Rules`].
+
+use std::{cmp::Ordering, fmt};
+
+use aoc_common::Location;
+
+use crate::{evaluation::Evaluation, rules::Rules, Error};
+
+/// A card as seen by a rule set: its strength is its rank in the deck order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Card {
+    label: char,
+    strength: u8,
+    wild: bool,
+}
+
+impl Card {
+    pub(crate) fn new(label: char, strength: u8, wild: bool) -> Self {
+        Card {
+            label,
+            strength,
+            wild,
+        }
+    }
+
+    pub fn label(&self) -> char {
+        self.label
+    }
+
+    pub fn strength(&self) -> u8 {
+        self.strength
+    }
+
+    pub fn is_wild(&self) -> bool {
+        self.wild
+    }
+}
+
+impl PartialOrd for Card {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Card {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.strength.cmp(&other.strength)
+    }
+}
+
+/// The sizes of the groups of equal cards, largest first: `[3, 2]` is a full
+/// house. The larger the first group the stronger the hand, then the second...
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HandType(Vec<u8>);
+
+impl HandType {
+    pub fn new(shape: impl Into<Vec<u8>>) -> Self {
+        let mut shape = shape.into();
+        shape.retain(|&size| size > 0);
+        shape.sort_by(|s1, s2| s2.cmp(s1));
+        HandType(shape)
+    }
+
+    pub fn shape(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+// "four of a kind" when the other cards are all different, "4+2+1" otherwise
+impl fmt::Display for HandType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const NUMBERS: [&str; 10] = [
+            "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+        let (groups, singles) = match self.0.iter().position(|&size| size == 1) {
+            Some(i) => self.0.split_at(i),
+            None => (&self.0[..], &[][..]),
+        };
+        let name = match *groups {
+            [] => "high card",
+            [2] => "one pair",
+            [2, 2] => "two pairs",
+            [3, 2] => "full house",
+            [n] if n > 2 && (n as usize) < NUMBERS.len() => {
+                return write!(f, "{} of a kind", NUMBERS[n as usize]);
+            }
+            _ => {
+                let sizes = groups
+                    .iter()
+                    .chain(singles)
+                    .map(u8::to_string)
+                    .collect::<Vec<_>>();
+                return f.write_str(&sizes.join("+"));
+            }
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Hand {
+    origin: String,
+    evaluation: Evaluation,
+    key: u64,
+}
+
+impl Hand {
+    /// Reads `s` (e.g. `"32T3K"`) under `rules`.
+    pub fn new(s: &str, rules: &Rules) -> Result<Self, Error> {
+        if s.chars().count() != rules.hand_size() {
+            return Err(Error::InvalidHand {
+                location: Location::new(1, 1),
+                size: rules.hand_size(),
+                text: s.to_string(),
+            });
+        }
+        let cards = s
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                rules.card(c).ok_or_else(|| Error::InvalidCard {
+                    location: Location::new(1, i + 1),
+                    text: c.to_string(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let evaluation = Evaluation::new(&cards, rules.tie_break());
+        let key = rules.key(&evaluation.hand_type, &evaluation.tie_break);
+        Ok(Hand {
+            origin: cards.iter().map(Card::label).collect(),
+            evaluation,
+            key,
+        })
+    }
+
+    /// The labels of the cards as spelled in the deck, e.g. `"32T3K"` for
+    /// `"32t3k"`.
+    pub fn cards(&self) -> &str {
+        &self.origin
+    }
+
+    pub fn hand_type(&self) -> &HandType {
+        &self.evaluation.hand_type
+    }
+
+    /// How the hand type and tie-break were found, to explain a ranking.
+    pub fn evaluation(&self) -> &Evaluation {
+        &self.evaluation
+    }
+
+    /// Sort key, stronger hands having greater keys: the rank of the hand type
+    /// then the strength of each card in tie-break order.
+    pub fn key(&self) -> u64 {
+        self.key
+    }
+}
+
+/// Hands are equal when they are as strong, like [`Ord for Hand`](Hand::cmp).
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for Hand {}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Only meaningful between hands read under the same rules.
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+/// Sum of each bid multiplied by the rank of its hand, the weakest hand having
+/// rank 1 and tied hands being ranked in input order, as in [`crate::Ranking`].
+pub fn total_winnings(hands_and_bids: &[(Hand, usize)]) -> usize {
+    let mut hands_and_bids = hands_and_bids.iter().collect::<Vec<_>>();
+    hands_and_bids.sort_by_key(|(hand, _)| hand.key());
+    hands_and_bids
+        .iter()
+        .enumerate()
+        .map(|(i, (_, bid))| (i + 1) * bid)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+    use crate::TieBreak;
+
+    #[test]
+    fn test_jacks() {
+        let rules = Rules::jacks();
+        let hand = |s| Hand::new(s, &rules).unwrap();
+        assert_eq!(hand("32T3K").hand_type(), &HandType::new([2, 1, 1, 1]));
+        assert_eq!(hand("32J3J").hand_type(), &HandType::new([2, 2, 1]));
+        assert!(hand("33332") > hand("2AAAA"));
+        assert!(hand("A2457") > hand("A2456"));
+        assert_eq!(hand("32t3k").cards(), "32T3K");
+        assert_eq!(hand("32t3k"), hand("32T3K"));
+    }
+
+    #[test]
+    fn test_jokers() {
+        let rules = Rules::jokers();
+        let hand = |s| Hand::new(s, &rules).unwrap();
+        assert_eq!(hand("32J3J").hand_type(), &HandType::new([4, 1]));
+        assert_eq!(hand("JKKK2").hand_type(), &HandType::new([4, 1]));
+        assert_eq!(hand("JJJJJ").hand_type(), &HandType::new([5]));
+        assert!(hand("JKKK2") < hand("QQQQ2"));
+        assert_eq!(hand("32T3K").key(), 0x1_2192B);
+        assert_eq!(hand("JJJJJ").key(), 0x6_00000);
+    }
+
+    #[test]
+    fn test_custom_rules() {
+        // deuces and jokers are both wild
+        let rules = Rules::new("2J3456789TQKA", "2J", TieBreak::FirstCard).unwrap();
+        let hand = |s| Hand::new(s, &rules).unwrap();
+        assert_eq!(hand("2J345").hand_type(), &HandType::new([3, 1, 1]));
+        assert_eq!(hand("2JQQK").hand_type(), &HandType::new([4, 1]));
+        assert_eq!(hand("22JJ2").hand_type(), &HandType::new([5]));
+        assert!(hand("2JQQK") < hand("J2QQK"));
+
+        let rules = Rules::new("23456789TJQKA", "", TieBreak::HighestCard).unwrap();
+        let hand = |s| Hand::new(s, &rules).unwrap();
+        assert!(hand("A2345") > hand("2345K"));
+        assert!(hand("A2345") < hand("A6345"));
+        assert_eq!(hand("A2345").cmp(&hand("5432A")), Ordering::Equal);
+        assert_eq!(hand("A2345"), hand("5432A"));
+        let hands = [hand("A2345"), hand("5432A")];
+        assert_eq!(hands.into_iter().collect::<BTreeSet<_>>().len(), 1);
+    }
+
+    #[test]
+    fn test_hand_sizes() {
+        let rules = Rules::jacks().with_hand_size(7).unwrap();
+        let hand = |s| Hand::new(s, &rules).unwrap();
+        assert_eq!(hand("KKKKKK2").hand_type().to_string(), "six of a kind");
+        assert_eq!(hand("AAAA222").hand_type().to_string(), "4+3");
+        assert_eq!(hand("AA22334").hand_type().to_string(), "2+2+2+1");
+        assert_eq!(hand("AA22345").hand_type().to_string(), "two pairs");
+        assert!(hand("2222333") > hand("AAAA234"));
+        assert!(hand("KKKKKK2") < hand("2222222"));
+        assert!(Hand::new("KKKKK", &rules).is_err());
+
+        let rules = Rules::new("abc", "c", TieBreak::FirstCard)
+            .unwrap()
+            .with_hand_size(3)
+            .unwrap();
+        let hand = |s| Hand::new(s, &rules).unwrap();
+        assert_eq!(hand("abc").hand_type(), &HandType::new([2, 1]));
+        assert_eq!(hand("ccc").hand_type().to_string(), "three of a kind");
+        assert!(hand("aab") < hand("bba"));
+    }
+
+    #[test]
+    fn test_multibyte_deck() {
+        let rules = Rules::new("♠♥♦♣", "", TieBreak::FirstCard)
+            .unwrap()
+            .with_hand_size(3)
+            .unwrap();
+        assert_eq!(
+            Hand::new("♠♥♠", &rules).unwrap().hand_type(),
+            &HandType::new([2, 1])
+        );
+        assert_eq!(
+            Hand::new("♠♥X", &rules),
+            Err(Error::InvalidCard {
+                location: Location::new(1, 3),
+                text: "X".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let rules = Rules::jacks();
+        assert_eq!(
+            Hand::new("T55J", &rules),
+            Err(Error::InvalidHand {
+                location: Location::new(1, 1),
+                size: 5,
+                text: "T55J".to_string(),
+            })
+        );
+        assert_eq!(
+            Hand::new("T55X5", &rules),
+            Err(Error::InvalidCard {
+                location: Location::new(1, 4),
+                text: "X".to_string(),
+            })
+        );
+    }
+}
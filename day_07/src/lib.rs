@@ -0,0 +1,333 @@
+pub mod evaluation;
+pub mod hand;
+pub mod ranking;
+pub mod rules;
+
+use aoc_common::{Location, Solution};
+
+pub use evaluation::{Assignment, Evaluation};
+pub use hand::{total_winnings, Card, Hand, HandType};
+pub use ranking::{Entry, Ranking};
+pub use rules::{Rules, TieBreak};
+
+pub struct Day07;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    #[error("{location}: expected `<hand> <bid>`, found {text:?}")]
+    InvalidLine { location: Location, text: String },
+    #[error("{location}: a hand has exactly {size} cards, found {text:?}")]
+    InvalidHand {
+        location: Location,
+        size: usize,
+        text: String,
+    },
+    #[error("{location}: invalid card {text:?}")]
+    InvalidCard { location: Location, text: String },
+    #[error("{location}: invalid bid {text:?}")]
+    InvalidBid { location: Location, text: String },
+    #[error("{location}: a bid is between 1 and {max}, found {bid}")]
+    BidOutOfRange {
+        location: Location,
+        bid: usize,
+        max: usize,
+    },
+    #[error("{}", invalid_lines(.0))]
+    InvalidLines(Vec<Error>),
+    #[error("card {card:?} appears twice in the deck order, again at position {position}")]
+    DuplicateCard { card: char, position: usize },
+    #[error("wildcard {card:?} is not in the deck order")]
+    UnknownWildcard { card: char },
+    #[error("a deck has at most 256 cards, found {size}")]
+    DeckTooLarge { size: usize },
+    #[error("a hand has at least one card")]
+    EmptyHand,
+    #[error("hands of {hand_size} cards from a deck of {deck_size} do not fit a 64-bit sort key")]
+    KeyTooLarge { hand_size: usize, deck_size: usize },
+}
+
+// "2 invalid lines:\n  line 2, column 7: invalid bid ...\n  line 5, ..."
+fn invalid_lines(errors: &[Error]) -> String {
+    let mut message = match errors.len() {
+        1 => "1 invalid line:".to_string(),
+        n => format!("{n} invalid lines:"),
+    };
+    for e in errors {
+        message.push_str(&format!("\n  {e}"));
+    }
+    message
+}
+
+impl Error {
+    fn within(self, origin: Location) -> Self {
+        match self {
+            Error::InvalidLine { location, text } => Error::InvalidLine {
+                location: location.within(origin),
+                text,
+            },
+            Error::InvalidHand {
+                location,
+                size,
+                text,
+            } => Error::InvalidHand {
+                location: location.within(origin),
+                size,
+                text,
+            },
+            Error::InvalidCard { location, text } => Error::InvalidCard {
+                location: location.within(origin),
+                text,
+            },
+            Error::InvalidBid { location, text } => Error::InvalidBid {
+                location: location.within(origin),
+                text,
+            },
+            Error::BidOutOfRange { location, bid, max } => Error::BidOutOfRange {
+                location: location.within(origin),
+                bid,
+                max,
+            },
+            Error::InvalidLines(errors) => {
+                Error::InvalidLines(errors.into_iter().map(|e| e.within(origin)).collect())
+            }
+            e @ (Error::DuplicateCard { .. }
+            | Error::UnknownWildcard { .. }
+            | Error::DeckTooLarge { .. }
+            | Error::EmptyHand
+            | Error::KeyTooLarge { .. }) => e,
+        }
+    }
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Parsed = Hands;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        let (jacks, jokers) = (Rules::jacks(), Rules::jokers());
+        let (jacks, jokers) = parse_lines(input, |line| {
+            let (cards, bid) = split_line(input, line)?;
+            Ok((
+                (read_hand(input, cards, &jacks)?, bid),
+                (read_hand(input, cards, &jokers)?, bid),
+            ))
+        })?
+        .into_iter()
+        .unzip();
+        Ok(Hands { jacks, jokers })
+    }
+
+    fn part1(hands: &Self::Parsed) -> Result<usize, Error> {
+        Ok(total_winnings(&hands.jacks))
+    }
+
+    fn part2(hands: &Self::Parsed) -> Result<usize, Error> {
+        Ok(total_winnings(&hands.jokers))
+    }
+}
+
+/// Every hand with its bid, read once with `J` as a jack (part 1) and once
+/// with `J` as a joker (part 2).
+#[derive(Debug)]
+pub struct Hands {
+    pub jacks: Vec<(Hand, usize)>,
+    pub jokers: Vec<(Hand, usize)>,
+}
+
+/// Largest bid, so that winnings stay far from overflowing.
+pub const MAX_BID: usize = u32::MAX as usize;
+
+/// Every hand of `input` read under `rules`, with its bid, one per line.
+///
+/// Hand and bid may be separated by any spaces or tabs, and blank lines are
+/// skipped. All invalid lines are reported at once, as [`Error::InvalidLines`].
+pub fn parse_hands(input: &str, rules: &Rules) -> Result<Vec<(Hand, usize)>, Error> {
+    parse_lines(input, |line| {
+        let (cards, bid) = split_line(input, line)?;
+        Ok((read_hand(input, cards, rules)?, bid))
+    })
+}
+
+// every non-blank line read by `read`, or the errors of all the invalid ones
+fn parse_lines<T>(
+    input: &str,
+    mut read: impl FnMut(&str) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    let mut parsed = vec![];
+    let mut errors = vec![];
+    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+        match read(line) {
+            Ok(t) => parsed.push(t),
+            Err(e) => errors.push(e),
+        }
+    }
+    match errors.is_empty() {
+        true => Ok(parsed),
+        false => Err(Error::InvalidLines(errors)),
+    }
+}
+
+// "32T3K 765" -> ("32T3K", 765)
+fn split_line<'a>(input: &str, line: &'a str) -> Result<(&'a str, usize), Error> {
+    let mut fields = line.split_whitespace();
+    let (Some(cards), Some(bid), None) = (fields.next(), fields.next(), fields.next()) else {
+        return Err(Error::InvalidLine {
+            location: Location::of(input, line),
+            text: line.to_string(),
+        });
+    };
+    let location = Location::of(input, bid);
+    let bid = bid.parse::<usize>().map_err(|_| Error::InvalidBid {
+        location,
+        text: bid.to_string(),
+    })?;
+    if !(1..=MAX_BID).contains(&bid) {
+        return Err(Error::BidOutOfRange {
+            location,
+            bid,
+            max: MAX_BID,
+        });
+    }
+    Ok((cards, bid))
+}
+
+// `cards`, a slice of `input`, with errors located in `input`
+fn read_hand(input: &str, cards: &str, rules: &Rules) -> Result<Hand, Error> {
+    Hand::new(cards, rules).map_err(|e| e.within(Location::of(input, cards)))
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Day07::part1(&Day07::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<usize, Error> {
+    Day07::part2(&Day07::parse(input)?)
+}
+
+#[cfg(test)]
+mod fixtures {
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |e: Error| Err(Error::InvalidLines(vec![e]));
+        assert_eq!(
+            Day07::parse("32T3K 765\nT55J5684").map(|_| ()),
+            error(Error::InvalidLine {
+                location: Location::new(2, 1),
+                text: "T55J5684".to_string(),
+            })
+        );
+        assert_eq!(
+            Day07::parse("32T3K 765\nT55J 684").map(|_| ()),
+            error(Error::InvalidHand {
+                location: Location::new(2, 1),
+                size: 5,
+                text: "T55J".to_string(),
+            })
+        );
+        assert_eq!(
+            Day07::parse("32T3K 765\nT55X5 684").map(|_| ()),
+            error(Error::InvalidCard {
+                location: Location::new(2, 4),
+                text: "X".to_string(),
+            })
+        );
+        assert_eq!(
+            Day07::parse("32T3K 765\nT55J5 -684").map(|_| ()),
+            error(Error::InvalidBid {
+                location: Location::new(2, 7),
+                text: "-684".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_tolerant_parser() {
+        let hands = parse_hands("  32t3k\t 765 \nT55J5\t\t684", &Rules::jacks()).unwrap();
+        assert_eq!(hands[0].0.hand_type(), &HandType::new([2, 1, 1, 1]));
+        assert_eq!(
+            hands[0].0.key(),
+            Hand::new("32T3K", &Rules::jacks()).unwrap().key()
+        );
+        assert_eq!(hands[1].1, 684);
+        let hands = Day07::parse("  32t3k\t 765 \nT55J5\t\t684").unwrap();
+        assert_eq!(hands.jacks[1].0.hand_type(), &HandType::new([3, 1, 1]));
+        assert_eq!(hands.jokers[1].0.hand_type(), &HandType::new([4, 1]));
+        assert_eq!(hands.jokers[0].1, 765);
+        let hands = parse_hands("32T3K 765\n\nKK677 28\n \t\n", &Rules::jacks()).unwrap();
+        assert_eq!(hands.len(), 2);
+
+        let input = "32T3K 765\nT55J5 0\nKK677\nKTJJT 4294967296\nQQQJA 483 1\nQQQJA 48x";
+        let Err(Error::InvalidLines(errors)) = parse_hands(input, &Rules::jacks()) else {
+            panic!("every line but the first is invalid");
+        };
+        assert_eq!(
+            errors,
+            vec![
+                Error::BidOutOfRange {
+                    location: Location::new(2, 7),
+                    bid: 0,
+                    max: MAX_BID
+                },
+                Error::InvalidLine {
+                    location: Location::new(3, 1),
+                    text: "KK677".to_string()
+                },
+                Error::BidOutOfRange {
+                    location: Location::new(4, 7),
+                    bid: 4294967296,
+                    max: MAX_BID
+                },
+                Error::InvalidLine {
+                    location: Location::new(5, 1),
+                    text: "QQQJA 483 1".to_string()
+                },
+                Error::InvalidBid {
+                    location: Location::new(6, 7),
+                    text: "48x".to_string()
+                },
+            ]
+        );
+        assert_eq!(
+            Error::InvalidLines(errors[1..3].to_vec()).to_string(),
+            "2 invalid lines:\n  \
+             line 3, column 1: expected `<hand> <bid>`, found \"KK677\"\n  \
+             line 4, column 7: a bid is between 1 and 4294967295, found 4294967296"
+        );
+    }
+
+    #[test]
+    fn test_custom_rules() {
+        let input = "32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483";
+        // jacks and tens are both wild, and the weakest cards
+        let rules = Rules::new("JT23456789QKA", "JT", TieBreak::FirstCard).unwrap();
+        let hands = parse_hands(input, &rules).unwrap();
+        assert_eq!(
+            hands
+                .iter()
+                .map(|(hand, _)| hand.hand_type().clone())
+                .collect::<Vec<_>>(),
+            vec![
+                HandType::new([3, 1, 1]),
+                HandType::new([5]),
+                HandType::new([2, 2, 1]),
+                HandType::new([5]),
+                HandType::new([4, 1]),
+            ]
+        );
+        assert_eq!(
+            total_winnings(&hands),
+            28 + 765 * 2 + 483 * 3 + 684 * 4 + 220 * 5
+        );
+    }
+}
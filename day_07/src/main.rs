@@ -1,10 +1,5 @@
-mod part1;
-mod part2;
+use std::process::ExitCode;
 
-use part1::part1;
-use part2::part2;
-
-fn main() {
-    println!("Part 1: {}", part1(include_str!("input.txt")));
-    println!("Part 2: {}", part2(include_str!("input.txt")));
+fn main() -> ExitCode {
+    aoc_common::main::<day_07::Day07>()
 }
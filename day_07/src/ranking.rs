@@ -0,0 +1,209 @@
+//! Every hand with its rank and winnings, to inspect a ranking rather than
+//! just sum it.
+
+use std::borrow::Cow;
+
+use serde::Serialize;
+
+use crate::{Hand, HandType};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Entry {
+    pub rank: usize,
+    /// 1-based line of the hand in the input, blank lines not counted.
+    pub line: usize,
+    pub cards: String,
+    #[serde(rename = "type", serialize_with = "as_display")]
+    pub hand_type: HandType,
+    pub bid: usize,
+    pub winnings: usize,
+    /// Lines of the hands exactly as strong as this one. Tied hands are ranked
+    /// in input order.
+    pub tied_with: Vec<usize>,
+}
+
+fn as_display<S: serde::Serializer>(
+    hand_type: &HandType,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(hand_type)
+}
+
+/// Hands from the weakest (rank 1) to the strongest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranking {
+    entries: Vec<Entry>,
+}
+
+impl Ranking {
+    /// Ranks hands read under the same rules, each with its bid.
+    pub fn new(hands_and_bids: &[(Hand, usize)]) -> Self {
+        let mut order = (0..hands_and_bids.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| hands_and_bids[i].0.key());
+        let mut entries = vec![];
+        for tied in order.chunk_by(|&i, &j| hands_and_bids[i].0.key() == hands_and_bids[j].0.key())
+        {
+            for &i in tied {
+                let (hand, bid) = &hands_and_bids[i];
+                let rank = entries.len() + 1;
+                entries.push(Entry {
+                    rank,
+                    line: i + 1,
+                    cards: hand.cards().to_string(),
+                    hand_type: hand.hand_type().clone(),
+                    bid: *bid,
+                    winnings: rank * bid,
+                    tied_with: tied.iter().filter(|&&j| j != i).map(|j| j + 1).collect(),
+                });
+            }
+        }
+        Ranking { entries }
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn total_winnings(&self) -> usize {
+        self.entries.iter().map(|entry| entry.winnings).sum()
+    }
+
+    /// Lines of each group of hands exactly as strong as one another.
+    pub fn ties(&self) -> Vec<Vec<usize>> {
+        self.entries
+            .chunk_by(|e1, e2| e2.tied_with.contains(&e1.line))
+            .filter(|tied| tied.len() > 1)
+            .map(|tied| tied.iter().map(|entry| entry.line).collect())
+            .collect()
+    }
+
+    /// Whether tied hands have different bids, so that the total winnings
+    /// depend on how ties are ordered.
+    pub fn is_ambiguous(&self) -> bool {
+        self.entries
+            .chunk_by(|e1, e2| e2.tied_with.contains(&e1.line))
+            .any(|tied| tied.iter().any(|entry| entry.bid != tied[0].bid))
+    }
+
+    /// One row per hand, tied lines separated by `;`.
+    pub fn to_csv(&self) -> String {
+        let mut csv = "rank,line,cards,type,bid,winnings,tied_with\n".to_string();
+        for entry in &self.entries {
+            let tied_with = entry
+                .tied_with
+                .iter()
+                .map(usize::to_string)
+                .collect::<Vec<_>>();
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{}\n",
+                entry.rank,
+                entry.line,
+                csv_field(&entry.cards),
+                csv_field(&entry.hand_type.to_string()),
+                entry.bid,
+                entry.winnings,
+                tied_with.join(";")
+            ));
+        }
+        csv
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.entries).expect("entries are plain data")
+    }
+}
+
+// quoted if it contains a separator, a quote or a line break, `"` doubled inside
+fn csv_field(text: &str) -> Cow<'_, str> {
+    match text.contains([',', '"', '\n', '\r']) {
+        true => Cow::Owned(format!("\"{}\"", text.replace('"', "\"\""))),
+        false => Cow::Borrowed(text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_hands, Rules, TieBreak};
+
+    const EXAMPLE: &str = include_str!("../fixtures/example.txt");
+
+    #[test]
+    fn test_ranking() {
+        let ranking = Ranking::new(&parse_hands(EXAMPLE, &Rules::jokers()).unwrap());
+        assert_eq!(ranking.total_winnings(), 5905);
+        assert_eq!(
+            ranking.entries()[4],
+            Entry {
+                rank: 5,
+                line: 4,
+                cards: "KTJJT".to_string(),
+                hand_type: HandType::new([4, 1]),
+                bid: 220,
+                winnings: 1100,
+                tied_with: vec![],
+            }
+        );
+        assert!(ranking.ties().is_empty());
+        assert!(!ranking.is_ambiguous());
+    }
+
+    #[test]
+    fn test_ties() {
+        let input = "KK677 28\n32T3K 765\nKK677 28\nKK677 10\n";
+        let ranking = Ranking::new(&parse_hands(input, &Rules::jacks()).unwrap());
+        assert_eq!(ranking.ties(), vec![vec![1, 3, 4]]);
+        assert_eq!(
+            ranking
+                .entries()
+                .iter()
+                .map(|entry| (entry.line, entry.tied_with.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (2, vec![]),
+                (1, vec![3, 4]),
+                (3, vec![1, 4]),
+                (4, vec![1, 3])
+            ]
+        );
+        assert!(ranking.is_ambiguous());
+        let ranking = Ranking::new(&parse_hands("KK677 28\nKK677 28", &Rules::jacks()).unwrap());
+        assert!(!ranking.is_ambiguous());
+    }
+
+    #[test]
+    fn test_exports() {
+        let input = "KK677 28\n32T3K 765\nKK677 10\n";
+        let ranking = Ranking::new(&parse_hands(input, &Rules::jacks()).unwrap());
+        assert_eq!(
+            ranking.to_csv(),
+            "rank,line,cards,type,bid,winnings,tied_with\n\
+             1,2,32T3K,one pair,765,765,\n\
+             2,1,KK677,two pairs,28,56,3\n\
+             3,3,KK677,two pairs,10,30,1\n"
+        );
+        let rules = Rules::new("23456789,QK\"A", "", TieBreak::FirstCard).unwrap();
+        let quoted = Ranking::new(&parse_hands("2,345 1\n\"\"AKQ 2", &rules).unwrap());
+        assert_eq!(
+            quoted.to_csv(),
+            "rank,line,cards,type,bid,winnings,tied_with\n\
+             1,1,\"2,345\",high card,1,1,\n\
+             2,2,\"\"\"\"\"AKQ\",one pair,2,4,\n"
+        );
+        assert_eq!(csv_field("a\nb"), "\"a\nb\"");
+
+        let json: serde_json::Value = serde_json::from_str(&ranking.to_json()).unwrap();
+        assert_eq!(
+            json[1],
+            serde_json::json!({
+                "rank": 2,
+                "line": 1,
+                "cards": "KK677",
+                "type": "two pairs",
+                "bid": 28,
+                "winnings": 56,
+                "tied_with": [3],
+            })
+        );
+    }
+}
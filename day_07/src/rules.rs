@@ -0,0 +1,265 @@
+//! What makes a card stronger than another, and which cards are wild.
+
+use crate::{
+    hand::{Card, HandType},
+    Error,
+};
+
+const MAX_DECK: usize = 256;
+const KEY_BITS: u32 = u64::BITS;
+
+/// How two hands of the same type are told apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreak {
+    /// The first card that differs, in the order the cards are written.
+    FirstCard,
+    /// The strongest card that differs, whatever its position in the hand.
+    HighestCard,
+}
+
+/// A rule set of Camel Cards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    order: Vec<char>,
+    wildcards: Vec<char>,
+    tie_break: TieBreak,
+    hand_size: usize,
+    // every hand type for `hand_size` cards, weakest first
+    hand_types: Vec<HandType>,
+}
+
+impl Rules {
+    /// `order` lists every card from the weakest to the strongest, `wildcards`
+    /// the ones counting as whichever card makes the hand strongest.
+    /// Hands have 5 cards, see [`Rules::with_hand_size`].
+    pub fn new(order: &str, wildcards: &str, tie_break: TieBreak) -> Result<Self, Error> {
+        let order = order.chars().collect::<Vec<_>>();
+        if order.len() > MAX_DECK {
+            return Err(Error::DeckTooLarge { size: order.len() });
+        }
+        if let Some((i, &card)) = order
+            .iter()
+            .enumerate()
+            .find(|&(i, card)| order[..i].contains(card))
+        {
+            return Err(Error::DuplicateCard {
+                card,
+                position: i + 1,
+            });
+        }
+        let wildcards = wildcards.chars().collect::<Vec<_>>();
+        if let Some(&card) = wildcards.iter().find(|card| !order.contains(card)) {
+            return Err(Error::UnknownWildcard { card });
+        }
+        Rules {
+            order,
+            wildcards,
+            tie_break,
+            hand_size: 0,
+            hand_types: vec![],
+        }
+        .with_hand_size(5)
+    }
+
+    /// The same rules with hands of `hand_size` cards, as long as a hand and
+    /// its type still fit a `u64` sort key.
+    pub fn with_hand_size(mut self, hand_size: usize) -> Result<Self, Error> {
+        if hand_size == 0 {
+            return Err(Error::EmptyHand);
+        }
+        let card_bits = u32::try_from(hand_size)
+            .unwrap_or(u32::MAX)
+            .saturating_mul(self.card_bits());
+        if card_bits >= KEY_BITS || card_bits + bits(nb_partitions(hand_size)) > KEY_BITS {
+            return Err(Error::KeyTooLarge {
+                hand_size,
+                deck_size: self.order.len(),
+            });
+        }
+        self.hand_types = partitions(hand_size as u8, hand_size as u8)
+            .into_iter()
+            .map(HandType::new)
+            .collect();
+        self.hand_types.sort();
+        self.hand_size = hand_size;
+        Ok(self)
+    }
+
+    /// Part 1: `J` is a jack.
+    pub fn jacks() -> Self {
+        Rules::new("23456789TJQKA", "", TieBreak::FirstCard).unwrap()
+    }
+
+    /// Part 2: `J` is a joker, the weakest card but a wild one.
+    pub fn jokers() -> Self {
+        Rules::new("J23456789TQKA", "J", TieBreak::FirstCard).unwrap()
+    }
+
+    pub fn tie_break(&self) -> TieBreak {
+        self.tie_break
+    }
+
+    pub fn hand_size(&self) -> usize {
+        self.hand_size
+    }
+
+    /// Every possible hand type, from the weakest to the strongest.
+    pub fn hand_types(&self) -> &[HandType] {
+        &self.hand_types
+    }
+
+    // bits taken by a card strength in a sort key
+    fn card_bits(&self) -> u32 {
+        bits(self.order.len()).max(1)
+    }
+
+    /// The sort key of a hand of type `hand_type` whose cards compare as
+    /// `tie_break`: the rank of its type, then each strength.
+    pub(crate) fn key(&self, hand_type: &HandType, tie_break: &[u8]) -> u64 {
+        let rank = self
+            .hand_types
+            .binary_search(hand_type)
+            .expect("hand types are all the partitions of the hand size");
+        tie_break.iter().fold(rank as u64, |key, &strength| {
+            key << self.card_bits() | strength as u64
+        })
+    }
+
+    /// The card labelled `label`, if it is part of the deck. A label in the
+    /// wrong case (`k` for `K`) is accepted unless the deck has both.
+    pub fn card(&self, label: char) -> Option<Card> {
+        let strength = self.order.iter().position(|&c| c == label).or_else(|| {
+            let mut alike =
+                (0..self.order.len()).filter(|&i| self.order[i].eq_ignore_ascii_case(&label));
+            alike.next().filter(|_| alike.next().is_none())
+        })?;
+        let label = self.order[strength];
+        Some(Card::new(
+            label,
+            strength as u8,
+            self.wildcards.contains(&label),
+        ))
+    }
+}
+
+// bits to write any of `0..n`
+fn bits(n: usize) -> u32 {
+    usize::BITS - n.saturating_sub(1).leading_zeros()
+}
+
+// number of `partitions(n, n)`, without listing them
+fn nb_partitions(n: usize) -> usize {
+    let mut ways = vec![0usize; n + 1];
+    ways[0] = 1;
+    for part in 1..=n {
+        for total in part..=n {
+            ways[total] = ways[total].saturating_add(ways[total - part]);
+        }
+    }
+    ways[n]
+}
+
+// ways to write `n` as a sum of parts no larger than `max`, largest part first
+fn partitions(n: u8, max: u8) -> Vec<Vec<u8>> {
+    if n == 0 {
+        return vec![vec![]];
+    }
+    (1..=max.min(n))
+        .flat_map(|first| {
+            partitions(n - first, first)
+                .into_iter()
+                .map(move |mut rest| {
+                    rest.insert(0, first);
+                    rest
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets() {
+        let jacks = Rules::jacks();
+        assert!(jacks.card('J').unwrap() < jacks.card('Q').unwrap());
+        assert!(!jacks.card('J').unwrap().is_wild());
+        let jokers = Rules::jokers();
+        assert!(jokers.card('J').unwrap() < jokers.card('2').unwrap());
+        assert!(jokers.card('J').unwrap().is_wild());
+        assert_eq!(jokers.card('X'), None);
+        assert_eq!(jokers.card('k'), jokers.card('K'));
+        assert!(jokers.card('j').unwrap().is_wild());
+        let both_cases = Rules::new("kK", "", TieBreak::FirstCard).unwrap();
+        assert_eq!(both_cases.card('k').unwrap().strength(), 0);
+        assert_eq!(
+            Rules::new("kKA", "", TieBreak::FirstCard)
+                .unwrap()
+                .card('a')
+                .unwrap()
+                .label(),
+            'A'
+        );
+        assert_eq!(both_cases.card('X'), None);
+        assert_eq!(
+            jokers
+                .hand_types()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "high card",
+                "one pair",
+                "two pairs",
+                "three of a kind",
+                "full house",
+                "four of a kind",
+                "five of a kind"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hand_sizes() {
+        let rules = Rules::jacks().with_hand_size(7).unwrap();
+        assert_eq!(rules.hand_types().len(), 15);
+        assert_eq!(rules.hand_types()[0].shape(), [1; 7]);
+        assert_eq!(rules.hand_types()[13].shape(), [6, 1]);
+        // 14 cards of 4 bits and 135 hand types on 8 bits
+        assert!(Rules::jacks().with_hand_size(14).is_ok());
+        assert_eq!(
+            Rules::jacks().with_hand_size(15),
+            Err(Error::KeyTooLarge {
+                hand_size: 15,
+                deck_size: 13
+            })
+        );
+        assert_eq!(Rules::jacks().with_hand_size(0), Err(Error::EmptyHand));
+        assert_eq!(partitions(4, 4).len(), 5);
+        assert_eq!(nb_partitions(14), 135);
+        assert_eq!(
+            (bits(1), bits(2), bits(13), bits(16), bits(17)),
+            (0, 1, 4, 4, 5)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            Rules::new("23425", "", TieBreak::FirstCard),
+            Err(Error::DuplicateCard {
+                card: '2',
+                position: 4
+            })
+        );
+        assert_eq!(
+            Rules::new("2345", "J", TieBreak::FirstCard),
+            Err(Error::UnknownWildcard { card: 'J' })
+        );
+        assert_eq!(
+            Rules::new(&"X".repeat(257), "", TieBreak::FirstCard),
+            Err(Error::DeckTooLarge { size: 257 })
+        );
+    }
+}
@@ -0,0 +1,216 @@
+//! Ghosts walking the network together, for any network rather than the
+//! puzzle's clean cycles.
+
+use std::{collections::HashMap, fmt};
+
+use aoc_common::math::{crt, gcd};
+
+use crate::{Direction, Error, Map};
+
+/// The walk of a ghost: a tail of steps, then a cycle repeating forever.
+/// The ghost is in the same state (node and next instruction) at steps `s` and
+/// `s + cycle` as soon as `s >= tail`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Walk {
+    pub tail: usize,
+    pub cycle: usize,
+    /// Steps on an end node, before the cycle starts.
+    pub tail_hits: Vec<usize>,
+    /// Steps on an end node during the first turn of the cycle, that is in
+    /// `tail..tail + cycle`.
+    pub cycle_hits: Vec<usize>,
+}
+
+impl Walk {
+    /// Whether the ghost is on an end node after `step` steps.
+    pub fn is_end_at(&self, step: usize) -> bool {
+        if step < self.tail {
+            return self.tail_hits.contains(&step);
+        }
+        let first_turn = self.tail + (step - self.tail) % self.cycle;
+        self.cycle_hits.contains(&first_turn)
+    }
+}
+
+/// When all ghosts are on an end node at the same time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Meeting {
+    After(usize),
+    Never,
+}
+
+impl fmt::Display for Meeting {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Meeting::After(steps) => write!(f, "{steps}"),
+            Meeting::Never => f.write_str("never"),
+        }
+    }
+}
+
+impl Map {
+    /// Follows the instructions from `start` until a state (node, position in
+    /// the instructions) repeats.
+    ///
+    /// # Panics
+    ///
+    /// If `start` is not a node of the map.
+    pub fn walk(&self, start: &str, is_end_pos: fn(&str) -> bool) -> Walk {
+        let mut seen = HashMap::new();
+        let mut hits = vec![];
+        let mut current_pos = start;
+        for step in 0.. {
+            let instruction = step % self.instructions.len();
+            if let Some(&tail) = seen.get(&(current_pos, instruction)) {
+                let (tail_hits, cycle_hits) = hits.iter().partition(|&&hit| hit < tail);
+                return Walk {
+                    tail,
+                    cycle: step - tail,
+                    tail_hits,
+                    cycle_hits,
+                };
+            }
+            seen.insert((current_pos, instruction), step);
+            if is_end_pos(current_pos) {
+                hits.push(step);
+            }
+            let (left, right) = &self.nodes[current_pos];
+            current_pos = match self.instructions[instruction] {
+                Direction::Left => left,
+                Direction::Right => right,
+            };
+        }
+        unreachable!("there are finitely many states")
+    }
+}
+
+/// First step at which every ghost is on an end node.
+///
+/// Before all ghosts are in their cycle, steps are checked one by one; after,
+/// each combination of a hit per cycle is a system of congruences solved with
+/// the generalized Chinese remainder theorem.
+pub fn meet(walks: &[Walk]) -> Result<Meeting, Error> {
+    let tail = walks.iter().map(|walk| walk.tail).max().unwrap_or(0);
+    if let Some(step) = (0..tail).find(|&step| walks.iter().all(|walk| walk.is_end_at(step))) {
+        return Ok(Meeting::After(step));
+    }
+    // (remainder, modulus) of the steps where all ghosts met so far are on an end node
+    let mut solutions = vec![(0, 1)];
+    for walk in walks {
+        let mut combined = vec![];
+        for &(r1, m1) in &solutions {
+            for &hit in &walk.cycle_hits {
+                let r2 = hit % walk.cycle;
+                match crt([(r1, m1), (r2, walk.cycle)]) {
+                    Some(solution) => combined.push(solution),
+                    None if r1.abs_diff(r2) % gcd(m1, walk.cycle) == 0 => {
+                        return Err(Error::TooManySteps)
+                    }
+                    None => (),
+                }
+            }
+        }
+        combined.sort();
+        combined.dedup();
+        solutions = combined;
+    }
+    let first_after_tail = |(r, m): (usize, usize)| match r.checked_sub(tail) {
+        Some(_) => Some(r),
+        None => m.checked_mul((tail - r).div_ceil(m))?.checked_add(r),
+    };
+    let steps = solutions
+        .into_iter()
+        .map(|solution| first_after_tail(solution).ok_or(Error::TooManySteps))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(steps
+        .into_iter()
+        .min()
+        .map_or(Meeting::Never, Meeting::After))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn walks(input: &str) -> Vec<Walk> {
+        let map = Map::from_str(input).unwrap();
+        ["11A", "22A"]
+            .iter()
+            .map(|start| map.walk(start, |s| s.ends_with('Z')))
+            .collect()
+    }
+
+    #[test]
+    fn test_walk() {
+        let map = Map::from_str(include_str!("../fixtures/example_ghosts.txt")).unwrap();
+        let walk = map.walk("22A", |s| s.ends_with('Z'));
+        assert_eq!(
+            walk,
+            Walk {
+                tail: 1,
+                cycle: 6,
+                tail_hits: vec![],
+                cycle_hits: vec![3, 6],
+            }
+        );
+        assert!(walk.is_end_at(9));
+        assert!(!walk.is_end_at(10));
+    }
+
+    #[test]
+    fn test_meet() {
+        // the puzzle's clean cycles: the first hit is also the cycle length
+        let ghosts = walks(include_str!("../fixtures/example_ghosts.txt"));
+        assert_eq!(meet(&ghosts), Ok(Meeting::After(6)));
+
+        // 11A reaches 11Z after 2 steps then loops on 11Z: any later step;
+        // 22A alternates between 22B and 22Z: every odd step
+        let input = "L\n\n11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (11Z, 11Z)\n\
+                     22A = (22Z, 22Z)\n22Z = (22B, 22B)\n22B = (22Z, 22Z)";
+        assert_eq!(meet(&walks(input)), Ok(Meeting::After(3)));
+
+        // both alternate but out of phase
+        let input = "L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n\
+                     22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22B, 22B)";
+        assert_eq!(meet(&walks(input)), Ok(Meeting::Never));
+        assert_eq!(Meeting::Never.to_string(), "never");
+
+        // a hit in a tail only
+        let input = "L\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11B, 11B)\n\
+                     22A = (22Z, 22Z)\n22Z = (22Z, 22Z)";
+        assert_eq!(meet(&walks(input)), Ok(Meeting::After(1)));
+    }
+
+    #[test]
+    fn test_meet_brute_force() {
+        // cycles of 3, 4 and 10 steps after tails of 2, 1 and 0 steps
+        let walks = [
+            Walk {
+                tail: 2,
+                cycle: 3,
+                tail_hits: vec![0],
+                cycle_hits: vec![3],
+            },
+            Walk {
+                tail: 1,
+                cycle: 4,
+                tail_hits: vec![],
+                cycle_hits: vec![2, 4],
+            },
+            Walk {
+                tail: 0,
+                cycle: 10,
+                tail_hits: vec![],
+                cycle_hits: vec![0, 7],
+            },
+        ];
+        let brute_force = (0..1000).find(|&step| walks.iter().all(|walk| walk.is_end_at(step)));
+        assert_eq!(
+            meet(&walks),
+            Ok(brute_force.map_or(Meeting::Never, Meeting::After))
+        );
+        assert_eq!(meet(&walks[1..]), Ok(Meeting::After(10)));
+    }
+}
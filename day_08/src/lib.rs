@@ -0,0 +1,245 @@
+pub mod ghosts;
+
+use aoc_common::{Location, Solution};
+use ghosts::Meeting;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+pub struct Day08;
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    #[error("missing the L/R instructions line")]
+    MissingInstructions,
+    #[error("{location}: invalid direction {text:?}, only L or R allowed")]
+    InvalidDirection { location: Location, text: String },
+    #[error("{location}: expected `AAA = (BBB, CCC)`, found {text:?}")]
+    InvalidNode { location: Location, text: String },
+    #[error("{location}: node {text:?} is not defined")]
+    UnknownNode { location: Location, text: String },
+    #[error("no node named {0:?}")]
+    MissingNode(String),
+    #[error("{to} cannot be reached from {from}")]
+    Unreachable { from: String, to: String },
+    #[error("no start node ending with 'A'")]
+    NoStartNode,
+    #[error("the ghosts meet after too many steps to count")]
+    TooManySteps,
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Parsed = Map;
+    type Answer1 = usize;
+    type Answer2 = Meeting;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        Map::from_str(input)
+    }
+
+    fn part1(map: &Self::Parsed) -> Result<usize, Error> {
+        let start_pos = "AAA";
+        if !map.nodes.contains_key(start_pos) {
+            return Err(Error::MissingNode(start_pos.to_string()));
+        }
+        let is_end_pos = |s: &str| s == "ZZZ";
+        map.get_count_for_node(start_pos, is_end_pos)
+            .ok_or(Error::Unreachable {
+                from: start_pos.to_string(),
+                to: "ZZZ".to_string(),
+            })
+    }
+
+    fn part2(map: &Self::Parsed) -> Result<Meeting, Error> {
+        let walks = map
+            .nodes
+            .keys()
+            .filter(|k| k.ends_with('A'))
+            .map(|k| map.walk(k, |s| s.ends_with('Z')))
+            .collect::<Vec<_>>();
+        if walks.is_empty() {
+            return Err(Error::NoStartNode);
+        }
+        ghosts::meet(&walks)
+    }
+}
+
+/// The instructions and the network of nodes. Every node reachable from a
+/// defined node is defined itself.
+#[derive(Debug)]
+pub struct Map {
+    instructions: Vec<Direction>,
+    nodes: BTreeMap<String, (String, String)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'L' => Ok(Self::Left),
+            'R' => Ok(Self::Right),
+            c => Err(c),
+        }
+    }
+}
+
+lazy_static! {
+    static ref RE: Regex = Regex::new(r"^(\w{3}) = \((\w{3}), (\w{3})\)$").unwrap();
+}
+
+// LRL = (MCG, TRC)
+fn parse_node_line(input: &str) -> Option<(&str, (&str, &str))> {
+    let (_, [node, left, right]) = RE.captures(input)?.extract();
+    Some((node, (left, right)))
+}
+
+impl FromStr for Map {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut lines = input.lines();
+        let instructions_line = lines
+            .next()
+            .filter(|line| !line.is_empty())
+            .ok_or(Error::MissingInstructions)?;
+        let instructions = instructions_line
+            .char_indices()
+            .map(|(i, c)| {
+                Direction::try_from(c).map_err(|c| Error::InvalidDirection {
+                    location: Location::of(input, &instructions_line[i..]),
+                    text: c.to_string(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let node_lines = lines
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                parse_node_line(line).ok_or_else(|| Error::InvalidNode {
+                    location: Location::of(input, line),
+                    text: line.to_string(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let nodes = node_lines
+            .iter()
+            .map(|&(node, (left, right))| (node.to_string(), (left.to_string(), right.to_string())))
+            .collect::<BTreeMap<_, _>>();
+        if let Some(unknown) = node_lines
+            .iter()
+            .flat_map(|(_, (left, right))| [left, right])
+            .find(|n| !nodes.contains_key(**n))
+        {
+            return Err(Error::UnknownNode {
+                location: Location::of(input, unknown),
+                text: unknown.to_string(),
+            });
+        }
+
+        Ok(Self {
+            instructions,
+            nodes,
+        })
+    }
+}
+
+impl Map {
+    pub fn instructions(&self) -> &[Direction] {
+        &self.instructions
+    }
+
+    /// Every node with its `(left, right)` neighbours.
+    pub fn nodes(&self) -> &BTreeMap<String, (String, String)> {
+        &self.nodes
+    }
+
+    /// Number of steps from `start_pos` to the first node matching `is_end_pos`,
+    /// if there is one on the way.
+    ///
+    /// # Panics
+    ///
+    /// If `start_pos` is not a node of the map.
+    pub fn get_count_for_node(
+        &self,
+        start_pos: &str,
+        is_end_pos: fn(&str) -> bool,
+    ) -> Option<usize> {
+        let walk = self.walk(start_pos, is_end_pos);
+        walk.tail_hits.into_iter().chain(walk.cycle_hits).next()
+    }
+}
+
+pub fn part1(input: &str) -> Result<usize, Error> {
+    Day08::part1(&Day08::parse(input)?)
+}
+
+pub fn part2(input: &str) -> Result<Meeting, Error> {
+    Day08::part2(&Day08::parse(input)?)
+}
+
+#[cfg(test)]
+mod fixtures {
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse_node_line("LRL = (MCG, TRC)"),
+            Some(("LRL", ("MCG", "TRC")))
+        );
+        assert_eq!(parse_node_line("LRL = (MCG, TRC"), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Map::from_str("LRX\n\nAAA = (AAA, AAA)").unwrap_err(),
+            Error::InvalidDirection {
+                location: Location::new(1, 3),
+                text: "X".to_string(),
+            }
+        );
+        assert_eq!(
+            Map::from_str("LR\n\nAAA = (AAA, AAA)\nBBB = AAA").unwrap_err(),
+            Error::InvalidNode {
+                location: Location::new(4, 1),
+                text: "BBB = AAA".to_string(),
+            }
+        );
+        assert_eq!(
+            Map::from_str("LR\n\nAAA = (AAA, AAA)\nBBB = (AAA, CCC)").unwrap_err(),
+            Error::UnknownNode {
+                location: Location::new(4, 13),
+                text: "CCC".to_string(),
+            }
+        );
+        assert_eq!(Map::from_str("").unwrap_err(), Error::MissingInstructions);
+        assert_eq!(
+            part1("LR\n\nBBB = (BBB, BBB)"),
+            Err(Error::MissingNode("AAA".to_string()))
+        );
+        assert_eq!(
+            part1("LR\n\nAAA = (BBB, AAA)\nBBB = (AAA, BBB)\nZZZ = (ZZZ, ZZZ)"),
+            Err(Error::Unreachable {
+                from: "AAA".to_string(),
+                to: "ZZZ".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,292 @@
+use std::collections::VecDeque;
+
+use aoc_common::{Location, Solution};
+
+pub struct Day09;
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    #[error("{location}: invalid number {text:?}")]
+    InvalidNumber { location: Location, text: String },
+    #[error("{location}: empty history")]
+    EmptyHistory { location: Location },
+}
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Parsed = Vec<Vec<isize>>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Parsed, Error> {
+        parse_input(input)
+    }
+
+    // every line of the history has at least one number: the parsed one is not
+    // empty, and differences stop at a single number
+    fn part1(numbers: &Self::Parsed) -> Result<isize, Error> {
+        Ok(numbers
+            .iter()
+            .map(|numbers| {
+                *compute_history_right(numbers)
+                    .first()
+                    .unwrap()
+                    .last()
+                    .unwrap()
+            })
+            .sum())
+    }
+
+    fn part2(numbers: &Self::Parsed) -> Result<isize, Error> {
+        Ok(numbers
+            .iter()
+            .map(|numbers| {
+                *compute_history_left(numbers)
+                    .first()
+                    .unwrap()
+                    .front()
+                    .unwrap()
+            })
+            .sum())
+    }
+}
+
+/// [10, 13, 16, 21, 30, 45] -> [3, 3, 3, 3, 3]
+pub fn difference_of_line(numbers: &[isize]) -> Vec<isize> {
+    numbers.windows(2).map(|w| w[1] - w[0]).collect()
+}
+
+/// The sequence followed by its successive differences, down to a line of zeros
+/// or, if the differences never get there, a single number. Either way the last
+/// line is constant, and so are its extrapolated values.
+pub fn all_differences(numbers: &[isize]) -> Vec<Vec<isize>> {
+    let mut res = vec![numbers.to_vec()];
+    while let Some(last) = res.last().filter(|line| !is_constant_end(line)) {
+        res.push(difference_of_line(last));
+    }
+    res
+}
+
+fn is_constant_end(line: &[isize]) -> bool {
+    line.len() <= 1 || line.iter().all(|&n| n == 0)
+}
+
+fn new_history_right(history: &[Vec<isize>]) -> Vec<Vec<isize>> {
+    let mut res = history.to_vec();
+    debug_assert!(is_constant_end(res.last().unwrap()));
+    let mut previous = 0;
+    for line in res.iter_mut().rev() {
+        line.push(line.last().unwrap() + previous);
+        previous = *line.last().unwrap();
+    }
+    res
+}
+
+/// [`all_differences`] with one value extrapolated at the end of every line.
+pub fn compute_history_right(numbers: &[isize]) -> Vec<Vec<isize>> {
+    let history = all_differences(numbers);
+    new_history_right(&history)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<isize>>, Error> {
+    input
+        .lines()
+        .map(|line| {
+            let numbers = line
+                .split_whitespace()
+                .map(|n| {
+                    n.parse().map_err(|_| Error::InvalidNumber {
+                        location: Location::of(input, n),
+                        text: n.to_string(),
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if numbers.is_empty() {
+                return Err(Error::EmptyHistory {
+                    location: Location::of(input, line),
+                });
+            }
+            Ok(numbers)
+        })
+        .collect()
+}
+
+pub fn part1(input: &str) -> Result<isize, Error> {
+    Day09::part1(&Day09::parse(input)?)
+}
+
+fn new_history_left(history: &[VecDeque<isize>]) -> Vec<VecDeque<isize>> {
+    let mut res = history.to_vec();
+    debug_assert!(res.last().unwrap().len() <= 1 || res.last().unwrap().iter().all(|&n| n == 0));
+    let mut previous = 0;
+    for line in res.iter_mut().rev() {
+        line.push_front(line.front().unwrap() - previous);
+        previous = *line.front().unwrap();
+    }
+    res
+}
+
+/// [`all_differences`] with one value extrapolated at the start of every line.
+pub fn compute_history_left(numbers: &[isize]) -> Vec<VecDeque<isize>> {
+    let history = all_differences(numbers);
+    let history: Vec<VecDeque<isize>> = history
+        .iter()
+        .map(|line| VecDeque::from(line.to_vec()))
+        .collect();
+    new_history_left(&history)
+}
+
+pub fn part2(input: &str) -> Result<isize, Error> {
+    Day09::part2(&Day09::parse(input)?)
+}
+
+#[cfg(test)]
+mod fixtures {
+    include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_difference_of_line() {
+        assert_eq!(difference_of_line(&[0, 3, 6, 9, 12, 15]), &[3, 3, 3, 3, 3]);
+        assert_eq!(difference_of_line(&[3, 3, 3, 3, 3]), &[0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_all_differences() {
+        let input = vec![0, 3, 6, 9, 12, 15];
+        assert_eq!(
+            all_differences(&input),
+            vec![
+                vec![0, 3, 6, 9, 12, 15],
+                vec![3, 3, 3, 3, 3],
+                vec![0, 0, 0, 0],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_new_history_right() {
+        let input = vec![
+            vec![0, 3, 6, 9, 12, 15],
+            vec![3, 3, 3, 3, 3],
+            vec![0, 0, 0, 0],
+        ];
+        assert_eq!(
+            new_history_right(&input),
+            vec![
+                vec![0, 3, 6, 9, 12, 15, 18],
+                vec![3, 3, 3, 3, 3, 3],
+                vec![0, 0, 0, 0, 0],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compute_history_right() {
+        let input = vec![0, 3, 6, 9, 12, 15];
+        assert_eq!(
+            compute_history_right(&input),
+            vec![
+                vec![0, 3, 6, 9, 12, 15, 18],
+                vec![3, 3, 3, 3, 3, 3],
+                vec![0, 0, 0, 0, 0],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compute_history_right_2() {
+        let input = vec![1, 3, 6, 10, 15, 21];
+        assert_eq!(
+            compute_history_right(&input),
+            vec![
+                vec![1, 3, 6, 10, 15, 21, 28],
+                vec![2, 3, 4, 5, 6, 7],
+                vec![1, 1, 1, 1, 1],
+                vec![0, 0, 0, 0],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_short_histories() {
+        // a single number is a constant sequence
+        assert_eq!(all_differences(&[5]), vec![vec![5]]);
+        assert_eq!(part1("5"), Ok(5));
+        assert_eq!(part2("5"), Ok(5));
+        // differences never reach zeros: the last single difference repeats
+        assert_eq!(
+            all_differences(&[1, 2, 4]),
+            vec![vec![1, 2, 4], vec![1, 2], vec![1]]
+        );
+        assert_eq!(part1("1 2 4"), Ok(7));
+        assert_eq!(part2("1 2 4"), Ok(1));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_input("0 3 6\n1 3 x6 10"),
+            Err(Error::InvalidNumber {
+                location: Location::new(2, 5),
+                text: "x6".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_input("0 3 6\n\n1 3 6"),
+            Err(Error::EmptyHistory {
+                location: Location::new(2, 1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_new_history_left() {
+        let input = vec![
+            VecDeque::from(vec![0, 3, 6, 9, 12, 15]),
+            VecDeque::from(vec![3, 3, 3, 3, 3]),
+            VecDeque::from(vec![0, 0, 0, 0]),
+        ];
+        assert_eq!(
+            new_history_left(&input),
+            vec![
+                VecDeque::from(vec![-3, 0, 3, 6, 9, 12, 15]),
+                VecDeque::from(vec![3, 3, 3, 3, 3, 3]),
+                VecDeque::from(vec![0, 0, 0, 0, 0]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compute_history_left() {
+        let input = vec![0, 3, 6, 9, 12, 15];
+        assert_eq!(
+            compute_history_left(&input),
+            vec![
+                VecDeque::from(vec![-3, 0, 3, 6, 9, 12, 15]),
+                VecDeque::from(vec![3, 3, 3, 3, 3, 3]),
+                VecDeque::from(vec![0, 0, 0, 0, 0]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compute_history_left_2() {
+        let input = vec![1, 3, 6, 10, 15, 21];
+        assert_eq!(
+            compute_history_left(&input),
+            vec![
+                VecDeque::from(vec![0, 1, 3, 6, 10, 15, 21]),
+                VecDeque::from(vec![1, 2, 3, 4, 5, 6]),
+                VecDeque::from(vec![1, 1, 1, 1, 1]),
+                VecDeque::from(vec![0, 0, 0, 0]),
+            ]
+        );
+    }
+}